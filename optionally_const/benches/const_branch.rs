@@ -0,0 +1,60 @@
+//! Compares a function monomorphized with a const type against the same function
+//! instantiated with a runtime `bool`.
+//!
+//! With the const type, `T::MAYBE_CONST` is `Some` in the monomorphized code, so the
+//! optimizer removes the runtime branch and the const instantiation should be at least
+//! as fast as the runtime one. Run with `cargo bench -p optionally_const`.
+
+use std::hint::black_box;
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use optionally_const::{ConstTypeBool, OptionallyConst, const_dispatch, const_type_instance};
+
+fn scale<T: OptionallyConst<bool>>(flag: T, x: u64) -> u64 {
+    if let Some(flag) = T::MAYBE_CONST {
+        if flag {
+            x.wrapping_mul(3)
+        } else {
+            x.wrapping_add(7)
+        }
+    } else if flag.into_value() {
+        x.wrapping_mul(3)
+    } else {
+        x.wrapping_add(7)
+    }
+}
+
+fn scale_dispatch<T: OptionallyConst<bool>>(flag: T, x: u64) -> u64 {
+    let pick = |flag: bool| {
+        if flag {
+            x.wrapping_mul(3)
+        } else {
+            x.wrapping_add(7)
+        }
+    };
+    const_dispatch(flag, pick, pick)
+}
+
+fn bench_const_branch(c: &mut Criterion) {
+    let mut group = c.benchmark_group("const_branch");
+
+    group.bench_function("runtime_bool", |b| {
+        b.iter(|| scale(black_box(true), black_box(42)));
+    });
+    group.bench_function("const_type_bool", |b| {
+        let flag: ConstTypeBool<true> = const_type_instance!(true);
+        b.iter(|| scale(black_box(flag), black_box(42)));
+    });
+    group.bench_function("const_dispatch_runtime_bool", |b| {
+        b.iter(|| scale_dispatch(black_box(true), black_box(42)));
+    });
+    group.bench_function("const_dispatch_const_type_bool", |b| {
+        let flag: ConstTypeBool<true> = const_type_instance!(true);
+        b.iter(|| scale_dispatch(black_box(flag), black_box(42)));
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_const_branch);
+criterion_main!(benches);
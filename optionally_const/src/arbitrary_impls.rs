@@ -0,0 +1,51 @@
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::{
+    ConstTypeBool, ConstTypeChar, ConstTypeDurationNanos, ConstTypeI8, ConstTypeI16, ConstTypeI32,
+    ConstTypeI64, ConstTypeI128, ConstTypeIsize, ConstTypeNonZeroU8, ConstTypeNonZeroU16,
+    ConstTypeNonZeroU32, ConstTypeNonZeroU64, ConstTypeNonZeroUsize, ConstTypeOrdering,
+    ConstTypeU8, ConstTypeU16, ConstTypeU32, ConstTypeU64, ConstTypeU128, ConstTypeUsize,
+    ConstTypeUsizeFromU32,
+};
+
+// Every const type has exactly one instance, so generating it needs no entropy.
+macro_rules! impl_arbitrary {
+    ($($carrier:ident<$param:ident: $param_ty:ty>;)*) => {
+        $(
+            impl<'a, const $param: $param_ty> Arbitrary<'a> for $carrier<$param> {
+                fn arbitrary(_u: &mut Unstructured<'a>) -> Result<Self> {
+                    Ok(Self::default())
+                }
+
+                fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+                    (0, Some(0))
+                }
+            }
+        )*
+    };
+}
+
+impl_arbitrary! {
+    ConstTypeBool<VAL: bool>;
+    ConstTypeU8<VAL: u8>;
+    ConstTypeU16<VAL: u16>;
+    ConstTypeU32<VAL: u32>;
+    ConstTypeU64<VAL: u64>;
+    ConstTypeU128<VAL: u128>;
+    ConstTypeUsize<VAL: usize>;
+    ConstTypeI8<VAL: i8>;
+    ConstTypeI16<VAL: i16>;
+    ConstTypeI32<VAL: i32>;
+    ConstTypeI64<VAL: i64>;
+    ConstTypeI128<VAL: i128>;
+    ConstTypeIsize<VAL: isize>;
+    ConstTypeChar<VAL: char>;
+    ConstTypeOrdering<VAL: i8>;
+    ConstTypeNonZeroU8<VAL: u8>;
+    ConstTypeNonZeroU16<VAL: u16>;
+    ConstTypeNonZeroU32<VAL: u32>;
+    ConstTypeNonZeroU64<VAL: u64>;
+    ConstTypeNonZeroUsize<VAL: usize>;
+    ConstTypeDurationNanos<NANOS: u128>;
+    ConstTypeUsizeFromU32<VAL: u32>;
+}
@@ -0,0 +1,108 @@
+use crate::{Const, ConstTypeBool, OptionallyConst};
+
+/// A trait for the type-level logical negation of [`ConstTypeBool`].
+///
+/// Ideally, the negation would be a type alias like
+/// `type Not<const VAL: bool> = ConstTypeBool<{ !VAL }>;`. However, generic parameters
+/// can't be used in const expressions on stable Rust, so the trait is implemented
+/// for both parameterizations of [`ConstTypeBool`] instead.
+///
+/// # Example
+///
+/// ```rust
+/// use optionally_const::{ConstNot, ConstTypeBool, Not, OptionallyConst};
+///
+/// fn negated<C: ConstNot>() -> Option<bool> {
+///     <C::Output as OptionallyConst<bool>>::MAYBE_CONST
+/// }
+///
+/// assert_eq!(negated::<ConstTypeBool<true>>(), Some(false));
+/// assert_eq!(<Not<ConstTypeBool<false>> as OptionallyConst<bool>>::MAYBE_CONST, Some(true));
+/// ```
+pub trait ConstNot {
+    /// The [`ConstTypeBool`] that represents the negated value.
+    type Output: Const<bool> + OptionallyConst<bool>;
+}
+
+impl ConstNot for ConstTypeBool<true> {
+    type Output = ConstTypeBool<false>;
+}
+
+impl ConstNot for ConstTypeBool<false> {
+    type Output = ConstTypeBool<true>;
+}
+
+/// A convenience type alias for the negation of the [`ConstTypeBool`] `C`.
+///
+/// For example, `Not<ConstTypeBool<true>>` is `ConstTypeBool<false>`.
+pub type Not<C> = <C as ConstNot>::Output;
+
+/// A trait for the type-level logical conjunction of [`ConstTypeBool`]s.
+///
+/// As with [`ConstNot`], `ConstTypeBool<{ A && B }>` can't be written on stable Rust,
+/// so the trait is implemented by the value of the left-hand side.
+///
+/// # Example
+///
+/// ```rust
+/// use optionally_const::{And, ConstAnd, ConstTypeBool, OptionallyConst};
+///
+/// fn both<L: ConstAnd<R>, R>() -> Option<bool> {
+///     <L::Output as OptionallyConst<bool>>::MAYBE_CONST
+/// }
+///
+/// assert_eq!(both::<ConstTypeBool<true>, ConstTypeBool<false>>(), Some(false));
+/// let _: ConstTypeBool<true> = <And<ConstTypeBool<true>, ConstTypeBool<true>>>::new();
+/// ```
+pub trait ConstAnd<Rhs> {
+    /// The [`ConstTypeBool`] that represents the conjunction of the values.
+    type Output: Const<bool> + OptionallyConst<bool>;
+}
+
+impl<const B: bool> ConstAnd<ConstTypeBool<B>> for ConstTypeBool<true> {
+    type Output = ConstTypeBool<B>;
+}
+
+impl<const B: bool> ConstAnd<ConstTypeBool<B>> for ConstTypeBool<false> {
+    type Output = ConstTypeBool<false>;
+}
+
+/// A trait for the type-level logical disjunction of [`ConstTypeBool`]s.
+///
+/// As with [`ConstNot`], `ConstTypeBool<{ A || B }>` can't be written on stable Rust,
+/// so the trait is implemented by the value of the left-hand side.
+///
+/// # Example
+///
+/// ```rust
+/// use optionally_const::{ConstOr, ConstTypeBool, OptionallyConst, Or};
+///
+/// fn either<L: ConstOr<R>, R>() -> Option<bool> {
+///     <L::Output as OptionallyConst<bool>>::MAYBE_CONST
+/// }
+///
+/// assert_eq!(either::<ConstTypeBool<false>, ConstTypeBool<true>>(), Some(true));
+/// let _: ConstTypeBool<false> = <Or<ConstTypeBool<false>, ConstTypeBool<false>>>::new();
+/// ```
+pub trait ConstOr<Rhs> {
+    /// The [`ConstTypeBool`] that represents the disjunction of the values.
+    type Output: Const<bool> + OptionallyConst<bool>;
+}
+
+impl<const B: bool> ConstOr<ConstTypeBool<B>> for ConstTypeBool<true> {
+    type Output = ConstTypeBool<true>;
+}
+
+impl<const B: bool> ConstOr<ConstTypeBool<B>> for ConstTypeBool<false> {
+    type Output = ConstTypeBool<B>;
+}
+
+/// A convenience type alias for the conjunction of the [`ConstTypeBool`]s `L` and `R`.
+///
+/// For example, `And<ConstTypeBool<true>, ConstTypeBool<false>>` is `ConstTypeBool<false>`.
+pub type And<L, R> = <L as ConstAnd<R>>::Output;
+
+/// A convenience type alias for the disjunction of the [`ConstTypeBool`]s `L` and `R`.
+///
+/// For example, `Or<ConstTypeBool<true>, ConstTypeBool<false>>` is `ConstTypeBool<true>`.
+pub type Or<L, R> = <L as ConstOr<R>>::Output;
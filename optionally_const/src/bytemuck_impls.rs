@@ -0,0 +1,46 @@
+use bytemuck::{Pod, Zeroable};
+
+use crate::{
+    ConstTypeBool, ConstTypeChar, ConstTypeDurationNanos, ConstTypeI8, ConstTypeI16, ConstTypeI32,
+    ConstTypeI64, ConstTypeI128, ConstTypeIsize, ConstTypeNonZeroU8, ConstTypeNonZeroU16,
+    ConstTypeNonZeroU32, ConstTypeNonZeroU64, ConstTypeNonZeroUsize, ConstTypeOrdering,
+    ConstTypeU8, ConstTypeU16, ConstTypeU32, ConstTypeU64, ConstTypeU128, ConstTypeUsize,
+    ConstTypeUsizeFromU32,
+};
+
+// SAFETY: the const types are zero-sized. A zero-sized type has no bytes,
+// so it has no padding, and the empty bit pattern, which is the only one
+// it can be represented with, is valid. The const types are `Copy` and `'static`.
+macro_rules! impl_bytemuck {
+    ($($carrier:ident<$param:ident: $param_ty:ty>;)*) => {
+        $(
+            unsafe impl<const $param: $param_ty> Zeroable for $carrier<$param> {}
+            unsafe impl<const $param: $param_ty> Pod for $carrier<$param> {}
+        )*
+    };
+}
+
+impl_bytemuck! {
+    ConstTypeBool<VAL: bool>;
+    ConstTypeU8<VAL: u8>;
+    ConstTypeU16<VAL: u16>;
+    ConstTypeU32<VAL: u32>;
+    ConstTypeU64<VAL: u64>;
+    ConstTypeU128<VAL: u128>;
+    ConstTypeUsize<VAL: usize>;
+    ConstTypeI8<VAL: i8>;
+    ConstTypeI16<VAL: i16>;
+    ConstTypeI32<VAL: i32>;
+    ConstTypeI64<VAL: i64>;
+    ConstTypeI128<VAL: i128>;
+    ConstTypeIsize<VAL: isize>;
+    ConstTypeChar<VAL: char>;
+    ConstTypeOrdering<VAL: i8>;
+    ConstTypeNonZeroU8<VAL: u8>;
+    ConstTypeNonZeroU16<VAL: u16>;
+    ConstTypeNonZeroU32<VAL: u32>;
+    ConstTypeNonZeroU64<VAL: u64>;
+    ConstTypeNonZeroUsize<VAL: usize>;
+    ConstTypeDurationNanos<NANOS: u128>;
+    ConstTypeUsizeFromU32<VAL: u32>;
+}
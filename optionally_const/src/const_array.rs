@@ -0,0 +1,84 @@
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+
+use crate::{Const, ConstVal, OptionallyConst, sealed};
+
+/// A [const type] that represents an array of `N` copies of the constant represented by `C`.
+///
+/// This is useful for splat constants, e.g. `ConstArray<ConstTypeU8<0xFF>, 4>` represents
+/// `[0xFF; 4]`.
+///
+/// [const type]: https://github.com/JohnScience/optionally_const/tree/main/optionally_const#const-type
+pub struct ConstArray<C, const N: usize>(PhantomData<C>);
+
+impl<C, const N: usize> ConstArray<C, N> {
+    /// Creates an instance of the const type.
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<C, const N: usize> Default for ConstArray<C, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C, const N: usize> Clone for ConstArray<C, N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<C, const N: usize> Copy for ConstArray<C, N> {}
+
+impl<C, const N: usize> PartialEq for ConstArray<C, N> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<C, const N: usize> Eq for ConstArray<C, N> {}
+
+// The type carries no runtime data, so hashing is a no-op.
+impl<C, const N: usize> Hash for ConstArray<C, N> {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+impl<T, C, const N: usize> sealed::Sealed<[T; N]> for ConstArray<C, N> {}
+
+impl<T, C, const N: usize> Const<[T; N]> for ConstArray<C, N>
+where
+    T: Copy,
+    C: Const<T>,
+{
+    const VALUE: [T; N] = [C::VALUE; N];
+}
+
+impl<C, const N: usize> ConstVal for ConstArray<C, N>
+where
+    C: ConstVal,
+    C::Value: Copy,
+{
+    type Value = [C::Value; N];
+}
+
+impl<T, C, const N: usize> OptionallyConst<[T; N]> for ConstArray<C, N>
+where
+    T: Copy + PartialEq,
+    C: Const<T>,
+{
+    const MAYBE_CONST: Option<[T; N]> = Some(<Self as Const<[T; N]>>::VALUE);
+
+    fn into_value(self) -> [T; N] {
+        <Self as Const<[T; N]>>::VALUE
+    }
+
+    fn try_from_value(value: [T; N]) -> Result<Self, [T; N]> {
+        if value == <Self as Const<[T; N]>>::VALUE {
+            Ok(Self::new())
+        } else {
+            Err(value)
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{Const, ConstVal, OptionallyConst, sealed};
+
+/// A [const type] that represents a constant `[u8; N]` value, e.g. a magic-byte sequence
+/// of a file format or a protocol.
+///
+/// Arrays can't be used as types of const generic parameters on stable Rust, so the bytes
+/// are packed into a `u128` in little-endian order. Consequently, only arrays of at most
+/// 16 bytes can be represented. Use the [`const_bytes!`](crate::const_bytes) macro instead of
+/// computing the parameters by hand.
+///
+/// On a nightly compiler, the [`ConstType`](https://docs.rs/optionally_const/latest/optionally_const/struct.ConstType.html)
+/// of the `generic_const_type` feature can be parameterized by a `[u8; N]` directly,
+/// e.g. `ConstType<[u8; 4], { *b"\x7fELF" }>`, which has no length limit.
+///
+/// # Example
+///
+/// ```rust
+/// use optionally_const::{const_bytes, Const, ConstTypeBytes, OptionallyConst};
+///
+/// fn has_magic<M: Const<[u8; 4]>>(header: &[u8], _magic: M) -> bool {
+///     header.starts_with(&M::VALUE)
+/// }
+///
+/// assert!(has_magic(b"\x7fELF\x02\x01", const_bytes!(b"\x7fELF")));
+/// assert!(!has_magic(b"MZ\x90\x00", const_bytes!(b"\x7fELF")));
+///
+/// let magic: [u8; 2] = const_bytes!(b"MZ").into_value();
+/// assert_eq!(magic, *b"MZ");
+/// assert_eq!(ConstTypeBytes::<2, 0x5a4d>::value(), *b"MZ");
+/// ```
+///
+/// [const type]: https://github.com/JohnScience/optionally_const/tree/main/optionally_const#const-type
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ConstTypeBytes<const N: usize, const BYTES: u128>;
+
+impl<const N: usize, const BYTES: u128> ConstTypeBytes<N, BYTES> {
+    /// Creates an instance of the const type.
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Returns the bytes represented by the const type.
+    ///
+    /// Unlike [`Const::value`], this function is `const`.
+    #[must_use]
+    pub const fn value() -> [u8; N] {
+        <Self as Const<[u8; N]>>::VALUE
+    }
+}
+
+impl<const N: usize, const BYTES: u128> sealed::Sealed<[u8; N]> for ConstTypeBytes<N, BYTES> {}
+
+impl<const N: usize, const BYTES: u128> Const<[u8; N]> for ConstTypeBytes<N, BYTES> {
+    const VALUE: [u8; N] = {
+        if N > 16 {
+            panic!("`ConstTypeBytes` can represent only arrays of at most 16 bytes");
+        }
+        let mut bytes = [0u8; N];
+        let mut i = 0;
+        while i < N {
+            bytes[i] = (BYTES >> (8 * i)) as u8;
+            i += 1;
+        }
+        bytes
+    };
+}
+
+impl<const N: usize, const BYTES: u128> ConstVal for ConstTypeBytes<N, BYTES> {
+    type Value = [u8; N];
+}
+
+impl<const N: usize, const BYTES: u128> OptionallyConst<[u8; N]> for ConstTypeBytes<N, BYTES> {
+    const MAYBE_CONST: Option<[u8; N]> = Some(<Self as Const<[u8; N]>>::VALUE);
+
+    fn into_value(self) -> [u8; N] {
+        Self::value()
+    }
+
+    fn try_from_value(value: [u8; N]) -> Result<Self, [u8; N]> {
+        if value == <Self as Const<[u8; N]>>::VALUE {
+            Ok(Self::new())
+        } else {
+            Err(value)
+        }
+    }
+}
+
+// Used by `const_bytes!`.
+#[doc(hidden)]
+pub const fn pack_bytes(bytes: &[u8]) -> u128 {
+    if bytes.len() > 16 {
+        panic!("`const_bytes!` supports only arrays of at most 16 bytes");
+    }
+    let mut packed: u128 = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        packed |= (bytes[i] as u128) << (8 * i);
+        i += 1;
+    }
+    packed
+}
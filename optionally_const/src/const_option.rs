@@ -0,0 +1,81 @@
+use core::marker::PhantomData;
+
+use crate::{Const, ConstVal, OptionallyConst, sealed};
+
+/// A [const type] that represents `Some` of the constant represented by `C`.
+///
+/// For example, `ConstSome<ConstTypeU8<42>>` represents `Some(42u8)`.
+///
+/// [const type]: https://github.com/JohnScience/optionally_const/tree/main/optionally_const#const-type
+pub struct ConstSome<C>(PhantomData<C>);
+
+/// A [const type] that represents the `None` value of type `Option<T>`.
+///
+/// [const type]: https://github.com/JohnScience/optionally_const/tree/main/optionally_const#const-type
+pub struct ConstNone<T>(PhantomData<T>);
+
+impl_marker_traits! {
+    ConstSome<C>;
+    ConstNone<T>;
+}
+
+impl<T, C> sealed::Sealed<Option<T>> for ConstSome<C> {}
+
+impl<T, C> Const<Option<T>> for ConstSome<C>
+where
+    C: Const<T>,
+{
+    const VALUE: Option<T> = Some(C::VALUE);
+}
+
+impl<C: ConstVal> ConstVal for ConstSome<C> {
+    type Value = Option<C::Value>;
+}
+
+impl<T, C> OptionallyConst<Option<T>> for ConstSome<C>
+where
+    T: Copy + PartialEq,
+    C: Const<T>,
+{
+    const MAYBE_CONST: Option<Option<T>> = Some(<Self as Const<Option<T>>>::VALUE);
+
+    fn into_value(self) -> Option<T> {
+        <Self as Const<Option<T>>>::VALUE
+    }
+
+    fn try_from_value(value: Option<T>) -> Result<Self, Option<T>> {
+        if value == <Self as Const<Option<T>>>::VALUE {
+            Ok(Self::new())
+        } else {
+            Err(value)
+        }
+    }
+}
+
+impl<T> sealed::Sealed<Option<T>> for ConstNone<T> {}
+
+impl<T> Const<Option<T>> for ConstNone<T> {
+    const VALUE: Option<T> = None;
+}
+
+impl<T> ConstVal for ConstNone<T> {
+    type Value = Option<T>;
+}
+
+impl<T> OptionallyConst<Option<T>> for ConstNone<T>
+where
+    T: Copy + PartialEq,
+{
+    const MAYBE_CONST: Option<Option<T>> = Some(None);
+
+    fn into_value(self) -> Option<T> {
+        None
+    }
+
+    fn try_from_value(value: Option<T>) -> Result<Self, Option<T>> {
+        match value {
+            None => Ok(Self::new()),
+            Some(_) => Err(value),
+        }
+    }
+}
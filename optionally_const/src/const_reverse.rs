@@ -0,0 +1,50 @@
+use core::cmp::Reverse;
+use core::marker::PhantomData;
+
+use crate::{Const, ConstVal, OptionallyConst, sealed};
+
+/// A [const type] that represents the constant represented by `C` wrapped in [`Reverse`].
+///
+/// For example, `ConstTypeReverse<ConstTypeU8<255>>` represents `Reverse(255u8)`.
+/// This way, the sort keys wrapped in [`Reverse`] can carry constants too.
+/// The runtime [`Reverse`] values are covered by the blanket impl of [`OptionallyConst`].
+///
+/// [const type]: https://github.com/JohnScience/optionally_const/tree/main/optionally_const#const-type
+pub struct ConstTypeReverse<C>(PhantomData<C>);
+
+impl_marker_traits! {
+    ConstTypeReverse<C>;
+}
+
+impl<T, C> sealed::Sealed<Reverse<T>> for ConstTypeReverse<C> {}
+
+impl<T, C> Const<Reverse<T>> for ConstTypeReverse<C>
+where
+    C: Const<T>,
+{
+    const VALUE: Reverse<T> = Reverse(C::VALUE);
+}
+
+impl<C: ConstVal> ConstVal for ConstTypeReverse<C> {
+    type Value = Reverse<C::Value>;
+}
+
+impl<T, C> OptionallyConst<Reverse<T>> for ConstTypeReverse<C>
+where
+    T: Copy + PartialEq,
+    C: Const<T>,
+{
+    const MAYBE_CONST: Option<Reverse<T>> = Some(<Self as Const<Reverse<T>>>::VALUE);
+
+    fn into_value(self) -> Reverse<T> {
+        <Self as Const<Reverse<T>>>::VALUE
+    }
+
+    fn try_from_value(value: Reverse<T>) -> Result<Self, Reverse<T>> {
+        if value == <Self as Const<Reverse<T>>>::VALUE {
+            Ok(Self::new())
+        } else {
+            Err(value)
+        }
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{Const, ConstVal, OptionallyConst, sealed};
+
+/// A [const type] that represents a constant `&'static str` value.
+///
+/// `&'static str` can't be used as a type of a const generic parameter on stable Rust,
+/// so the string is encoded as its length `LEN` and its UTF-8 bytes packed into a `u128`
+/// in little-endian order. Consequently, only strings of at most 16 bytes can be represented.
+/// Use the [`const_str!`](crate::const_str) macro instead of computing the parameters by hand.
+///
+/// On a nightly compiler, the `adt_const_params` and `unsized_const_params` features allow
+/// `struct S<const VAL: &'static str>;`, which has no length limit. However, the
+/// [`ConstType`](https://docs.rs/optionally_const/latest/optionally_const/struct.ConstType.html)
+/// of the `generic_const_type` feature can't be parameterized by a `&'static str` yet because
+/// the type of its const parameter would contain a lifetime.
+///
+/// # Example
+///
+/// ```rust
+/// use optionally_const::{const_str, Const, ConstTypeStr, OptionallyConst};
+///
+/// let name = const_str!("optionally");
+/// let name: &'static str = name.into_value();
+/// assert_eq!(name, "optionally");
+/// assert_eq!(ConstTypeStr::<2, 0x6b6f>::value(), "ok");
+///
+/// fn value_of<C: Const<&'static str>>(_: C) -> &'static str {
+///     C::VALUE
+/// }
+///
+/// assert_eq!(value_of(const_str!("")), "");
+/// ```
+///
+/// [const type]: https://github.com/JohnScience/optionally_const/tree/main/optionally_const#const-type
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct ConstTypeStr<const LEN: usize, const BYTES: u128>;
+
+impl<const LEN: usize, const BYTES: u128> ConstTypeStr<LEN, BYTES> {
+    const UTF8: [u8; LEN] = {
+        let mut utf8 = [0u8; LEN];
+        let mut i = 0;
+        while i < LEN {
+            utf8[i] = (BYTES >> (8 * i)) as u8;
+            i += 1;
+        }
+        utf8
+    };
+
+    /// Creates an instance of the const type.
+    pub const fn new() -> Self {
+        Self
+    }
+
+    /// Returns the string represented by the const type.
+    ///
+    /// Unlike [`Const::value`], this function is `const`.
+    #[must_use]
+    pub const fn value() -> &'static str {
+        <Self as Const<&'static str>>::VALUE
+    }
+}
+
+impl<const LEN: usize, const BYTES: u128> sealed::Sealed<&'static str>
+    for ConstTypeStr<LEN, BYTES>
+{
+}
+
+impl<const LEN: usize, const BYTES: u128> Const<&'static str> for ConstTypeStr<LEN, BYTES> {
+    const VALUE: &'static str = {
+        if LEN > 16 {
+            panic!("`ConstTypeStr` can represent only strings of at most 16 bytes");
+        }
+        match core::str::from_utf8(&Self::UTF8) {
+            Ok(value) => value,
+            Err(_) => panic!("`ConstTypeStr` must be parameterized by valid UTF-8"),
+        }
+    };
+}
+
+impl<const LEN: usize, const BYTES: u128> ConstVal for ConstTypeStr<LEN, BYTES> {
+    type Value = &'static str;
+}
+
+impl<const LEN: usize, const BYTES: u128> OptionallyConst<&'static str>
+    for ConstTypeStr<LEN, BYTES>
+{
+    const MAYBE_CONST: Option<&'static str> = Some(<Self as Const<&'static str>>::VALUE);
+
+    fn into_value(self) -> &'static str {
+        Self::value()
+    }
+
+    fn try_from_value(value: &'static str) -> Result<Self, &'static str> {
+        if value == <Self as Const<&'static str>>::VALUE {
+            Ok(Self::new())
+        } else {
+            Err(value)
+        }
+    }
+}
+
+// Used by `const_str!`.
+#[doc(hidden)]
+pub const fn pack_str(s: &str) -> u128 {
+    let bytes = s.as_bytes();
+    if bytes.len() > 16 {
+        panic!("`const_str!` supports only strings of at most 16 bytes");
+    }
+    let mut packed: u128 = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        packed |= (bytes[i] as u128) << (8 * i);
+        i += 1;
+    }
+    packed
+}
@@ -0,0 +1,49 @@
+use core::marker::PhantomData;
+use core::num::Wrapping;
+
+use crate::{Const, ConstVal, OptionallyConst, sealed};
+
+/// A [const type] that represents the constant represented by `C` wrapped in [`Wrapping`].
+///
+/// For example, `ConstTypeWrapping<ConstTypeU8<255>>` represents `Wrapping(255u8)`.
+/// The runtime [`Wrapping`] values are covered by the blanket impl of [`OptionallyConst`].
+///
+/// [const type]: https://github.com/JohnScience/optionally_const/tree/main/optionally_const#const-type
+pub struct ConstTypeWrapping<C>(PhantomData<C>);
+
+impl_marker_traits! {
+    ConstTypeWrapping<C>;
+}
+
+impl<T, C> sealed::Sealed<Wrapping<T>> for ConstTypeWrapping<C> {}
+
+impl<T, C> Const<Wrapping<T>> for ConstTypeWrapping<C>
+where
+    C: Const<T>,
+{
+    const VALUE: Wrapping<T> = Wrapping(C::VALUE);
+}
+
+impl<C: ConstVal> ConstVal for ConstTypeWrapping<C> {
+    type Value = Wrapping<C::Value>;
+}
+
+impl<T, C> OptionallyConst<Wrapping<T>> for ConstTypeWrapping<C>
+where
+    T: Copy + PartialEq,
+    C: Const<T>,
+{
+    const MAYBE_CONST: Option<Wrapping<T>> = Some(<Self as Const<Wrapping<T>>>::VALUE);
+
+    fn into_value(self) -> Wrapping<T> {
+        <Self as Const<Wrapping<T>>>::VALUE
+    }
+
+    fn try_from_value(value: Wrapping<T>) -> Result<Self, Wrapping<T>> {
+        if value == <Self as Const<Wrapping<T>>>::VALUE {
+            Ok(Self::new())
+        } else {
+            Err(value)
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use defmt::{Format, Formatter};
+
+use crate::{
+    ConstSeed, ConstTypeBool, ConstTypeChar, ConstTypeI8, ConstTypeI16, ConstTypeI32, ConstTypeI64,
+    ConstTypeI128, ConstTypeIsize, ConstTypeU8, ConstTypeU16, ConstTypeU32, ConstTypeU64,
+    ConstTypeU128, ConstTypeUsize,
+};
+
+impl<const VAL: bool> Format for ConstTypeBool<VAL> {
+    fn format(&self, f: Formatter<'_>) {
+        VAL.format(f);
+    }
+}
+
+impl<const VAL: u8> Format for ConstTypeU8<VAL> {
+    fn format(&self, f: Formatter<'_>) {
+        VAL.format(f);
+    }
+}
+
+impl<const VAL: u16> Format for ConstTypeU16<VAL> {
+    fn format(&self, f: Formatter<'_>) {
+        VAL.format(f);
+    }
+}
+
+impl<const VAL: u32> Format for ConstTypeU32<VAL> {
+    fn format(&self, f: Formatter<'_>) {
+        VAL.format(f);
+    }
+}
+
+impl<const VAL: u64> Format for ConstTypeU64<VAL> {
+    fn format(&self, f: Formatter<'_>) {
+        VAL.format(f);
+    }
+}
+
+impl<const VAL: u128> Format for ConstTypeU128<VAL> {
+    fn format(&self, f: Formatter<'_>) {
+        VAL.format(f);
+    }
+}
+
+impl<const VAL: usize> Format for ConstTypeUsize<VAL> {
+    fn format(&self, f: Formatter<'_>) {
+        VAL.format(f);
+    }
+}
+
+impl<const VAL: i8> Format for ConstTypeI8<VAL> {
+    fn format(&self, f: Formatter<'_>) {
+        VAL.format(f);
+    }
+}
+
+impl<const VAL: i16> Format for ConstTypeI16<VAL> {
+    fn format(&self, f: Formatter<'_>) {
+        VAL.format(f);
+    }
+}
+
+impl<const VAL: i32> Format for ConstTypeI32<VAL> {
+    fn format(&self, f: Formatter<'_>) {
+        VAL.format(f);
+    }
+}
+
+impl<const VAL: i64> Format for ConstTypeI64<VAL> {
+    fn format(&self, f: Formatter<'_>) {
+        VAL.format(f);
+    }
+}
+
+impl<const VAL: i128> Format for ConstTypeI128<VAL> {
+    fn format(&self, f: Formatter<'_>) {
+        VAL.format(f);
+    }
+}
+
+impl<const VAL: isize> Format for ConstTypeIsize<VAL> {
+    fn format(&self, f: Formatter<'_>) {
+        VAL.format(f);
+    }
+}
+
+impl<const VAL: char> Format for ConstTypeChar<VAL> {
+    fn format(&self, f: Formatter<'_>) {
+        VAL.format(f);
+    }
+}
+
+impl<const SEED: u64> Format for ConstSeed<SEED> {
+    fn format(&self, f: Formatter<'_>) {
+        SEED.format(f);
+    }
+}
@@ -0,0 +1,81 @@
+use core::hash::{Hash, Hasher};
+use core::marker::{ConstParamTy_, PhantomData};
+
+use crate::{Const, ConstVal, OptionallyConst, sealed};
+
+/// The [const type] of type `T`, whose parameterizations represent the constants of type `T`.
+///
+/// This type is available only with the `generic_const_type` feature, which
+/// requires a nightly compiler because it relies on the unstable `adt_const_params`
+/// and `generic_const_parameter_types` features.
+///
+/// [const type]: https://github.com/JohnScience/optionally_const/tree/main/optionally_const#const-type
+pub struct ConstType<T: ConstParamTy_, const VAL: T>(PhantomData<T>);
+
+impl<T: ConstParamTy_, const VAL: T> ConstType<T, VAL> {
+    /// Creates an instance of the const type.
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+
+    /// Returns the constant represented by the const type.
+    ///
+    /// Unlike [`Const::value`], this function is `const`.
+    #[must_use]
+    pub const fn value() -> T {
+        VAL
+    }
+}
+
+impl<T: ConstParamTy_, const VAL: T> Default for ConstType<T, VAL> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ConstParamTy_, const VAL: T> Clone for ConstType<T, VAL> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: ConstParamTy_, const VAL: T> Copy for ConstType<T, VAL> {}
+
+impl<T: ConstParamTy_, const VAL: T> PartialEq for ConstType<T, VAL> {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<T: ConstParamTy_, const VAL: T> Eq for ConstType<T, VAL> {}
+
+// The type carries no runtime data, so hashing is a no-op.
+impl<T: ConstParamTy_, const VAL: T> Hash for ConstType<T, VAL> {
+    fn hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+impl<T: ConstParamTy_, const VAL: T> sealed::Sealed<T> for ConstType<T, VAL> {}
+
+impl<T: ConstParamTy_, const VAL: T> Const<T> for ConstType<T, VAL> {
+    const VALUE: T = VAL;
+}
+
+impl<T: ConstParamTy_, const VAL: T> ConstVal for ConstType<T, VAL> {
+    type Value = T;
+}
+
+impl<T: ConstParamTy_ + Copy, const VAL: T> OptionallyConst<T> for ConstType<T, VAL> {
+    const MAYBE_CONST: Option<T> = Some(VAL);
+
+    fn into_value(self) -> T {
+        Self::value()
+    }
+
+    fn try_from_value(value: T) -> Result<Self, T> {
+        if value == VAL {
+            Ok(Self::new())
+        } else {
+            Err(value)
+        }
+    }
+}
@@ -0,0 +1,101 @@
+use core::hash::{BuildHasher, Hasher};
+
+use crate::OptionallyConst;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// A seeded [FNV-1a] hasher whose seed is optionally constant.
+///
+/// When the seed is a [`ConstSeed`], the seed is not stored at runtime and the
+/// initial state of the hasher is known at compile time.
+///
+/// The hasher is deterministic and is **not** resistant to HashDoS attacks.
+///
+/// [FNV-1a]: https://en.wikipedia.org/wiki/Fowler%E2%80%93Noll%E2%80%93Vo_hash_function
+/// [`ConstSeed`]: crate::ConstSeed
+#[derive(Clone)]
+pub struct SeededHasher<S> {
+    seed: S,
+    state: u64,
+}
+
+impl<S> SeededHasher<S>
+where
+    S: OptionallyConst<u64>,
+{
+    /// Creates a new hasher with the given seed.
+    pub fn new(seed: S) -> Self {
+        let seed_value: u64 = match S::MAYBE_CONST {
+            Some(seed_value) => seed_value,
+            None => seed.into_value(),
+        };
+        Self {
+            seed,
+            state: FNV_OFFSET_BASIS ^ seed_value,
+        }
+    }
+
+    /// Returns the seed of the hasher.
+    pub fn seed(&self) -> S {
+        self.seed
+    }
+}
+
+impl<S> Hasher for SeededHasher<S>
+where
+    S: OptionallyConst<u64>,
+{
+    fn finish(&self) -> u64 {
+        self.state
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state ^= u64::from(*byte);
+            self.state = self.state.wrapping_mul(FNV_PRIME);
+        }
+    }
+}
+
+/// A [`BuildHasher`] that builds [`SeededHasher`]s with an optionally constant seed.
+///
+/// # Example
+///
+/// ```rust
+/// use core::hash::{BuildHasher, Hasher};
+/// use optionally_const::{ConstSeed, SeededBuildHasher};
+///
+/// let mut hasher = SeededBuildHasher::new(ConstSeed::<42>).build_hasher();
+/// hasher.write(b"optionally_const");
+/// let const_hash = hasher.finish();
+///
+/// let mut hasher = SeededBuildHasher::new(42u64).build_hasher();
+/// hasher.write(b"optionally_const");
+/// assert_eq!(hasher.finish(), const_hash);
+/// ```
+#[derive(Clone, Copy)]
+pub struct SeededBuildHasher<S> {
+    seed: S,
+}
+
+impl<S> SeededBuildHasher<S>
+where
+    S: OptionallyConst<u64>,
+{
+    /// Creates a new [`BuildHasher`] with the given seed.
+    pub fn new(seed: S) -> Self {
+        Self { seed }
+    }
+}
+
+impl<S> BuildHasher for SeededBuildHasher<S>
+where
+    S: OptionallyConst<u64>,
+{
+    type Hasher = SeededHasher<S>;
+
+    fn build_hasher(&self) -> Self::Hasher {
+        SeededHasher::new(self.seed)
+    }
+}
@@ -1,19 +1,243 @@
 #![doc = include_str!("../README.md")]
 #![cfg_attr(not(test), no_std)]
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(
+    feature = "generic_const_type",
+    feature(
+        adt_const_params,
+        generic_const_parameter_types,
+        const_param_ty_trait
+    ),
+    allow(incomplete_features)
+)]
 
 #[cfg(feature = "derive")]
 #[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
 pub use optionally_const_macros::FieldlessEnumConstType;
 
-// struct ConstType<T, const VAL: T>;
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use optionally_const_macros::ConstValue;
+
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+pub use optionally_const_macros::OptionallyConstNewtype;
+
+// Implements the common traits for the const types with a single `PhantomData` field.
+// The types carry no runtime data, so the impls don't depend on the type parameters.
+// Since the field makes them tuple structs rather than unit structs, their instances
+// are created with `new`.
+macro_rules! impl_marker_traits {
+    ($($carrier:ident<$param:ident>;)*) => {
+        $(
+            impl<$param> $carrier<$param> {
+                /// Creates an instance of the const type.
+                pub const fn new() -> Self {
+                    Self(::core::marker::PhantomData)
+                }
+            }
+
+            impl<$param> Default for $carrier<$param> {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+
+            impl<$param> Clone for $carrier<$param> {
+                fn clone(&self) -> Self {
+                    *self
+                }
+            }
+
+            impl<$param> Copy for $carrier<$param> {}
+
+            impl<$param> PartialEq for $carrier<$param> {
+                fn eq(&self, _other: &Self) -> bool {
+                    true
+                }
+            }
+
+            impl<$param> Eq for $carrier<$param> {}
 
-// type ConstTypeBool<const VAL: bool> = ConstType<bool, VAL>;
+            impl<$param> ::core::hash::Hash for $carrier<$param> {
+                fn hash<H: ::core::hash::Hasher>(&self, _state: &mut H) {}
+            }
+        )*
+    };
+}
+
+mod bool_ops;
+mod const_array;
+mod const_bytes;
+mod const_option;
+mod const_reverse;
+mod const_str;
+mod const_wrapping;
+mod hash;
+
+pub use bool_ops::{And, ConstAnd, ConstNot, ConstOr, Not, Or};
+pub use const_array::ConstArray;
+pub use const_bytes::ConstTypeBytes;
+pub use const_option::{ConstNone, ConstSome};
+pub use const_reverse::ConstTypeReverse;
+pub use const_str::ConstTypeStr;
+pub use const_wrapping::ConstTypeWrapping;
+pub use hash::{SeededBuildHasher, SeededHasher};
+
+#[cfg(feature = "generic_const_type")]
+mod generic_const_type;
+
+#[cfg(feature = "serde")]
+mod serde_impls;
+
+#[cfg(feature = "defmt")]
+mod defmt_impls;
+
+#[cfg(feature = "bytemuck")]
+mod bytemuck_impls;
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls;
+
+#[cfg(feature = "generic_const_type")]
+#[cfg_attr(docsrs, doc(cfg(feature = "generic_const_type")))]
+pub use generic_const_type::ConstType;
 
 #[doc(hidden)]
 pub mod hidden {
-    #[derive(Clone, Copy, PartialEq)]
+    #[cfg(feature = "serde")]
+    pub use serde;
+
+    #[cfg(feature = "defmt")]
+    pub use defmt;
+
+    #[cfg(feature = "bytemuck")]
+    pub use bytemuck;
+
+    #[cfg(feature = "arbitrary")]
+    pub use arbitrary;
+
+    #[cfg(feature = "proptest")]
+    pub use proptest;
+
+    pub use optionally_const_macros::__const_type_instance_literal as const_type_instance_literal;
+
+    pub use crate::const_bytes::pack_bytes;
+
+    pub use crate::const_str::pack_str;
+
+    pub use crate::__fieldless_enum_serde_impls as fieldless_enum_serde_impls;
+
+    pub use crate::__fieldless_enum_defmt_impls as fieldless_enum_defmt_impls;
+
+    pub use crate::__fieldless_enum_bytemuck_impls as fieldless_enum_bytemuck_impls;
+
+    pub use crate::__fieldless_enum_arbitrary_impls as fieldless_enum_arbitrary_impls;
+
+    pub use crate::__fieldless_enum_proptest_impls as fieldless_enum_proptest_impls;
+
+    #[cfg(not(feature = "generic_const_type"))]
+    #[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
     pub struct ConstTypeBool<const VAL: bool>;
+
+    #[cfg(feature = "generic_const_type")]
+    pub type ConstTypeBool<const VAL: bool> = super::ConstType<bool, VAL>;
+
+    use crate::OptionallyConst;
+
+    // Used by `const_match!`.
+    pub fn split_const<T, U>(carrier: U) -> Result<T, T>
+    where
+        U: OptionallyConst<T>,
+    {
+        match U::MAYBE_CONST {
+            Some(value) => Ok(value),
+            None => Err(carrier.into_value()),
+        }
+    }
+
+    // Used by `const_type_instance!` because the `ConstTypeBool` type alias
+    // can't be used as a value when the `generic_const_type` feature is enabled.
+    pub const fn const_type_bool<const VAL: bool>() -> ConstTypeBool<VAL> {
+        #[cfg(not(feature = "generic_const_type"))]
+        {
+            ConstTypeBool::<VAL>
+        }
+        #[cfg(feature = "generic_const_type")]
+        {
+            super::ConstType::new()
+        }
+    }
+
+    // Used by the `FieldlessEnumConstType` derive macro to restrict the methods and the impls
+    // of the const types to the discriminants of the variants, and to report invalid
+    // discriminants with a readable message.
+    #[diagnostic::on_unimplemented(
+        message = "`{D}` is not a valid discriminant of any variant of `{Self}`",
+        label = "invalid discriminant",
+        note = "the discriminant is expected to be written as `{{ {Self}::Variant as usize }}`"
+    )]
+    pub trait ValidDiscriminant<const D: usize> {}
+
+    pub use crate::numeric_const_types::*;
+
+    #[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct ConstTypeChar<const VAL: char>;
+
+    #[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+    pub struct ConstTypeOrdering<const VAL: i8>;
+
+    #[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    pub struct ConstTypeNonZeroU8<const VAL: u8>;
+
+    #[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    pub struct ConstTypeNonZeroU16<const VAL: u16>;
+
+    #[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    pub struct ConstTypeNonZeroU32<const VAL: u32>;
+
+    #[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    pub struct ConstTypeNonZeroU64<const VAL: u64>;
+
+    #[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    pub struct ConstTypeNonZeroUsize<const VAL: usize>;
+
+    #[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    pub struct ConstTypeDurationNanos<const NANOS: u128>;
+
+    #[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    pub struct ConstTypeUsizeFromU32<const VAL: u32>;
+
+    // The const types are unit structs, so they can be constructed in const contexts.
+    macro_rules! impl_new {
+        ($($carrier:ident<$param:ident: $param_ty:ty>;)*) => {
+            $(
+                impl<const $param: $param_ty> $carrier<$param> {
+                    /// Creates an instance of the const type.
+                    pub const fn new() -> Self {
+                        Self
+                    }
+                }
+            )*
+        };
+    }
+
+    #[cfg(not(feature = "generic_const_type"))]
+    impl_new! {
+        ConstTypeBool<VAL: bool>;
+    }
+
+    impl_new! {
+        ConstTypeChar<VAL: char>;
+        ConstTypeOrdering<VAL: i8>;
+        ConstTypeNonZeroU8<VAL: u8>;
+        ConstTypeNonZeroU16<VAL: u16>;
+        ConstTypeNonZeroU32<VAL: u32>;
+        ConstTypeNonZeroU64<VAL: u64>;
+        ConstTypeNonZeroUsize<VAL: usize>;
+        ConstTypeDurationNanos<NANOS: u128>;
+        ConstTypeUsizeFromU32<VAL: u32>;
+    }
 }
 
 /// A convenience type alias that represents a constant boolean value.
@@ -22,9 +246,130 @@ pub mod hidden {
 /// `struct ConstType<T, const VAL: T>` with `T = bool`.
 ///
 /// However, defining such a struct is impossible in Rust at the time
-/// of writing this code.
+/// of writing this code without the `generic_const_type` feature, which requires
+/// a nightly compiler. With the feature enabled, this is an alias for
+/// `ConstType<bool, VAL>`.
 pub type ConstTypeBool<const VAL: bool> = hidden::ConstTypeBool<VAL>;
 
+/// A convenience type alias that represents a constant `char` value.
+///
+/// Both `char` and `ConstTypeChar` satisfy the same `OptionallyConst<char>` bound,
+/// so a single generic function can accept either of them.
+///
+/// # Example
+///
+/// ```rust
+/// use optionally_const::{const_type_instance, OptionallyConst};
+///
+/// fn classify<T: OptionallyConst<char>>(c: T) -> &'static str {
+///     let c: char = c.into_value();
+///     if c.is_ascii_digit() {
+///         "digit"
+///     } else if c.is_alphabetic() {
+///         "letter"
+///     } else {
+///         "other"
+///     }
+/// }
+///
+/// assert_eq!(classify('7'), "digit");
+/// assert_eq!(classify(const_type_instance!('x')), "letter");
+/// assert_eq!(classify(const_type_instance!(' ')), "other");
+/// ```
+pub type ConstTypeChar<const VAL: char> = hidden::ConstTypeChar<VAL>;
+
+/// A convenience type alias that represents a constant [`Ordering`](core::cmp::Ordering) value.
+///
+/// Since `Ordering` can't be used as a type of a const generic parameter on stable Rust,
+/// the value is encoded as an `i8`: `-1` for `Less`, `0` for `Equal`, and `1` for `Greater`.
+/// Using any other value results in a compile-time error.
+pub type ConstTypeOrdering<const VAL: i8> = hidden::ConstTypeOrdering<VAL>;
+
+/// A convenience type alias that represents a constant [`NonZeroU8`](core::num::NonZeroU8) value.
+///
+/// The value is encoded as a `u8`. Using `0` results in a compile-time error.
+pub type ConstTypeNonZeroU8<const VAL: u8> = hidden::ConstTypeNonZeroU8<VAL>;
+
+/// A convenience type alias that represents a constant [`NonZeroU16`](core::num::NonZeroU16) value.
+///
+/// The value is encoded as a `u16`. Using `0` results in a compile-time error.
+pub type ConstTypeNonZeroU16<const VAL: u16> = hidden::ConstTypeNonZeroU16<VAL>;
+
+/// A convenience type alias that represents a constant [`NonZeroU32`](core::num::NonZeroU32) value.
+///
+/// Since `NonZeroU32` can't be used as a type of a const generic parameter,
+/// the value is encoded as a `u32`. Using `0` results in a compile-time error.
+///
+/// ```rust
+/// use core::num::NonZeroU32;
+/// use optionally_const::{Const, ConstTypeNonZeroU32};
+///
+/// assert_eq!(<ConstTypeNonZeroU32<5> as Const<NonZeroU32>>::VALUE.get(), 5);
+/// ```
+///
+/// ```rust,compile_fail
+/// use core::num::NonZeroU32;
+/// use optionally_const::{Const, ConstTypeNonZeroU32};
+///
+/// let _ = <ConstTypeNonZeroU32<0> as Const<NonZeroU32>>::VALUE;
+/// ```
+pub type ConstTypeNonZeroU32<const VAL: u32> = hidden::ConstTypeNonZeroU32<VAL>;
+
+/// A convenience type alias that represents a constant [`NonZeroU64`](core::num::NonZeroU64) value.
+///
+/// The value is encoded as a `u64`. Using `0` results in a compile-time error.
+pub type ConstTypeNonZeroU64<const VAL: u64> = hidden::ConstTypeNonZeroU64<VAL>;
+
+/// A convenience type alias that represents a constant [`NonZeroUsize`](core::num::NonZeroUsize) value.
+///
+/// The value is encoded as a `usize`. Using `0` results in a compile-time error.
+pub type ConstTypeNonZeroUsize<const VAL: usize> = hidden::ConstTypeNonZeroUsize<VAL>;
+
+/// A convenience type alias that represents a constant [`Duration`](core::time::Duration) value.
+///
+/// Since `Duration` can't be used as a type of a const generic parameter,
+/// the value is encoded as the total number of nanoseconds. A number of nanoseconds
+/// whose whole seconds don't fit into a `u64` results in a compile-time error.
+///
+/// The [`const_duration!`] macro computes the nanoseconds from a value and a unit.
+///
+/// ```rust
+/// use core::time::Duration;
+/// use optionally_const::{const_duration, Const, ConstTypeDurationNanos};
+///
+/// let timeout: ConstTypeDurationNanos<5_000_000> = const_duration!(5, "ms");
+/// let value = <ConstTypeDurationNanos<5_000_000> as Const<Duration>>::VALUE;
+/// assert_eq!(value, Duration::from_millis(5));
+/// ```
+pub type ConstTypeDurationNanos<const NANOS: u128> = hidden::ConstTypeDurationNanos<NANOS>;
+
+/// A convenience type alias that represents a constant `usize` value given as a `u32`.
+///
+/// This is useful for passing the constants that originate as `u32`s to the generic code
+/// bounded on [`OptionallyConst<usize>`]. The conversion is checked at compile time, so on
+/// the targets where `usize` is narrower than `u32`, e.g. 16-bit targets, a value exceeding
+/// `usize::MAX` results in a compile-time error rather than a truncated value.
+///
+/// ```rust
+/// use optionally_const::{Const, ConstTypeUsizeFromU32};
+///
+/// // Compiles on 32-bit and 64-bit targets but would fail to compile on 16-bit ones.
+/// assert_eq!(<ConstTypeUsizeFromU32<70_000> as Const<usize>>::VALUE, 70_000);
+/// ```
+pub type ConstTypeUsizeFromU32<const VAL: u32> = hidden::ConstTypeUsizeFromU32<VAL>;
+
+/// A type that represents the constant `()` value.
+///
+/// This makes `()` usable as the `T` in [`OptionallyConst<T>`] pipelines.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConstUnit;
+
+/// A type that represents a compile-time-known hash seed.
+///
+/// See [`SeededHasher`] for a hasher that can be specialized on a const seed.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConstSeed<const SEED: u64>;
+
 /// A trait that can be used to represent a type that is either
 /// type `T` or a type that represents a constant value of type `T`.
 ///
@@ -47,6 +392,7 @@ pub trait OptionallyConst<T>: Clone + Copy + PartialEq + Sized {
     const MAYBE_CONST: Option<T>;
 
     /// Converts the instance of the type into a value of type `T`.
+    #[must_use]
     fn into_value(self) -> T;
 
     /// Converts the value of type `T` into an instance of the type.
@@ -59,34 +405,444 @@ pub trait OptionallyConst<T>: Clone + Copy + PartialEq + Sized {
 
     /// Converts the value of type `U` into an instance of the type.
     ///
+    /// The conversion is based on the equality of the represented values rather than on
+    /// the equality of the types. Converting between two distinct types that represent
+    /// equal values succeeds, and converting a runtime value into `T` always succeeds.
+    ///
+    /// The value is compared only by [`OptionallyConst::try_from_value`] on `Self`, so `T`
+    /// doesn't have to implement any traits, including [`PartialEq`].
+    ///
     /// # Errors
     ///
     /// If the `other` value is an instance of a [const type] and `Self` is a parametrization
     /// of a [const type], this function will return an error if the associated constants on
-    /// `Self` and `U` do not match.
+    /// `Self` and `U` do not match. More generally, this function returns the original `another`
+    /// value intact whenever [`OptionallyConst::try_from_value`] on `Self` rejects its value.
     ///
     /// [const type]: https://github.com/JohnScience/optionally_const/tree/main/optionally_const#const-type
     fn try_from_another<U>(another: U) -> Result<Self, U>
     where
         U: OptionallyConst<T>,
-        T: Clone + Copy + PartialEq,
     {
         Self::try_from_value(another.into_value())
             .ok()
             .ok_or(another)
     }
+
+    /// Compares the value represented by the instance with a runtime value of type `T`.
+    ///
+    /// Const types of the same type `T` are distinct types, so they can't be compared
+    /// with each other directly. This method compares the underlying values instead.
+    #[must_use]
+    fn cmp_value(self, other: T) -> core::cmp::Ordering
+    where
+        T: Ord,
+    {
+        self.into_value().cmp(&other)
+    }
+
+    /// Converts the instance into an instance of the [const type] `U`.
+    ///
+    /// `U` must be constructible from its value alone, i.e. its
+    /// [`OptionallyConst::try_from_value`] must succeed for [`Const::VALUE`].
+    /// This holds for unit-like const types, such as the ones provided by this crate
+    /// or generated by the derive macro.
+    ///
+    /// # Errors
+    ///
+    /// If the value represented by the instance does not match the associated constant
+    /// on `U`, this function will return `self`.
+    ///
+    /// [const type]: https://github.com/JohnScience/optionally_const/tree/main/optionally_const#const-type
+    fn try_into_const<U>(self) -> Result<U, Self>
+    where
+        U: Const<T> + OptionallyConst<T>,
+        T: PartialEq,
+    {
+        let value: T = self.into_value();
+        if value == <U as Const<T>>::VALUE {
+            U::try_from_value(value).map_err(|_| self)
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Converts the value of type `T` into an instance of the type, like
+    /// [`OptionallyConst::try_from_value`], but with a descriptive error.
+    ///
+    /// # Errors
+    ///
+    /// If the value does not match the value of the optionally constant
+    /// type instance, this function will return a [`ConstMismatch`] carrying the value
+    /// and, if the type represents a constant, the expected value.
+    fn try_from_value_err(value: T) -> Result<Self, ConstMismatch<T>> {
+        Self::try_from_value(value).map_err(|value| ConstMismatch {
+            value,
+            expected: Self::MAYBE_CONST,
+        })
+    }
+
+    /// Checks whether the instance and another optionally constant value represent
+    /// the same value of type `T`.
+    ///
+    /// Unlike [`PartialEq`], this compares instances of different types, e.g.
+    /// a [const type] instance and a runtime value.
+    ///
+    /// [const type]: https://github.com/JohnScience/optionally_const/tree/main/optionally_const#const-type
+    #[must_use]
+    fn value_eq<U>(self, other: U) -> bool
+    where
+        U: OptionallyConst<T>,
+        T: PartialEq,
+    {
+        self.into_value() == other.into_value()
+    }
+
+    /// Erases the constness of the instance and returns the plain value of type `T`.
+    ///
+    /// This is an alias for [`OptionallyConst::into_value`] that communicates the intent
+    /// at the call sites that purposely drop to the runtime path.
+    #[must_use]
+    fn to_dynamic(self) -> T {
+        self.into_value()
+    }
+
+    /// Returns the value represented by the instance without consuming it.
+    ///
+    /// This is useful when the instance is a field of a struct that is accessed by reference.
+    #[must_use]
+    fn value_by_ref(&self) -> T {
+        (*self).into_value()
+    }
+
+    /// Feeds the value represented by the instance into the given [`Hasher`](core::hash::Hasher).
+    ///
+    /// Unlike [`Hash::hash`](core::hash::Hash::hash), this hashes the value rather than
+    /// the instance, so a [const type] instance and a runtime value hash identically
+    /// when they represent equal values.
+    ///
+    /// [const type]: https://github.com/JohnScience/optionally_const/tree/main/optionally_const#const-type
+    fn hash_value<H: core::hash::Hasher>(&self, state: &mut H)
+    where
+        T: core::hash::Hash,
+    {
+        self.value_by_ref().hash(state);
+    }
+
+    /// Returns the constant represented by the type, if any, for interop with
+    /// [`Option`]-based APIs.
+    ///
+    /// This is [`OptionallyConst::MAYBE_CONST`], so the runtime values yield `None`
+    /// regardless of what they hold. See [`OptionallyConst::into_option`] for the
+    /// interpretation that always returns `Some`.
+    #[must_use]
+    fn const_value(self) -> Option<T> {
+        Self::MAYBE_CONST
+    }
+
+    /// Returns the value represented by the instance wrapped in `Some`.
+    ///
+    /// Unlike [`OptionallyConst::const_value`], this returns `Some` for both
+    /// the [const type] instances and the runtime values.
+    ///
+    /// It's not named `value` to avoid the ambiguity with [`Const::value`].
+    ///
+    /// [const type]: https://github.com/JohnScience/optionally_const/tree/main/optionally_const#const-type
+    #[must_use]
+    fn into_option(self) -> Option<T> {
+        Some(self.into_value())
+    }
+
+    /// Applies the fallible conversion `f` to the value represented by the instance.
+    ///
+    /// When the type represents a constant, the argument of `f` is that constant, so
+    /// the call can be constant-folded by the optimizer if `f` is simple enough to be inlined.
+    /// However, `f` is not guaranteed to be evaluated at compile time. For that, apply
+    /// a `const fn` to [`Const::VALUE`] in a const context.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error returned by `f`.
+    fn try_map<U, E, F>(self, f: F) -> Result<U, E>
+    where
+        F: FnOnce(T) -> Result<U, E>,
+    {
+        f(self.into_value())
+    }
+
+    /// Calls `on_const` with the constant if the type represents one, or
+    /// `on_runtime` with the value of the instance otherwise.
+    ///
+    /// This is the method form of [`const_dispatch`], so it can be used inline in method chains.
+    ///
+    /// ```rust
+    /// use optionally_const::{const_type_instance, OptionallyConst};
+    ///
+    /// fn describe<T: OptionallyConst<u8>>(level: T) -> String {
+    ///     level.fold(|level| format!("const {level}"), |level| format!("runtime {level}"))
+    /// }
+    ///
+    /// assert_eq!(describe(const_type_instance!(3: u8)), "const 3");
+    /// assert_eq!(describe(3u8), "runtime 3");
+    /// ```
+    fn fold<R>(self, on_const: impl FnOnce(T) -> R, on_runtime: impl FnOnce(T) -> R) -> R {
+        const_dispatch(self, on_const, on_runtime)
+    }
+
+    /// Returns the constant represented by the type.
+    ///
+    /// Trait methods can't be `const` at the time of writing this code, so misuse is
+    /// detected only at runtime. Use [`assert_const!`] to reject runtime types at compile time.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the message `msg` if [`OptionallyConst::MAYBE_CONST`] is `None`,
+    /// i.e. if the type does not represent a constant.
+    fn expect_const(self, msg: &str) -> T {
+        match Self::MAYBE_CONST {
+            Some(value) => value,
+            None => panic!("{msg}"),
+        }
+    }
+
+    /// Returns the constant represented by the type without checking that it represents one.
+    ///
+    /// This is the unchecked counterpart of [`OptionallyConst::expect_const`] for the code
+    /// where the caller knows that the type is a [const type] but the optimizer fails
+    /// to remove the branch on [`OptionallyConst::MAYBE_CONST`].
+    ///
+    /// # Safety
+    ///
+    /// [`OptionallyConst::MAYBE_CONST`] must be `Some`, i.e. the type must represent a constant.
+    /// Calling this method on a runtime value, e.g. a `bool`, is *[undefined behavior]*.
+    ///
+    /// [const type]: https://github.com/JohnScience/optionally_const/tree/main/optionally_const#const-type
+    /// [undefined behavior]: https://doc.rust-lang.org/reference/behavior-considered-undefined.html
+    #[must_use]
+    unsafe fn as_const_unchecked(self) -> T {
+        // SAFETY: the caller guarantees that `MAYBE_CONST` is `Some`.
+        unsafe { Self::MAYBE_CONST.unwrap_unchecked() }
+    }
+}
+
+/// The module with the supertrait that seals [`Const`].
+///
+/// The module is public only so that [`impl_const!`] and the derive macros can refer to it.
+#[doc(hidden)]
+pub mod sealed {
+    /// The supertrait of [`Const<T>`](crate::Const).
+    ///
+    /// It's implemented for the const types provided by this crate, by the derive macros,
+    /// and by [`impl_const!`](crate::impl_const). Implementing it by hand is not supported.
+    pub trait Sealed<T> {}
+
+    /// The supertrait of [`IsConst<T>`](crate::IsConst).
+    ///
+    /// It's implemented by
+    /// [`impl_optionally_const_via_const!`](crate::impl_optionally_const_via_const).
+    /// Implementing it by hand is not supported.
+    pub trait IsConstSealed<T> {}
 }
 
 /// A trait whose types-implementors represent a constant value of type `T`.
-pub trait Const<T> {
+///
+/// The trait is sealed, so that the set of its implementors stays under the control of
+/// this crate. Besides the const types provided by this crate and the types generated by
+/// the derive macros, it can be implemented for user-defined types with [`impl_const!`].
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` doesn't represent a constant value of type `{T}`",
+    label = "const type instance expected here",
+    note = "the implementors of `Const<T>` are types that represent a single compile-time value \
+        of type `T`, such as `ConstTypeBool<true>`, rather than the values of type `T` themselves",
+    note = "for a fieldless enum, derive the const type with `#[derive(FieldlessEnumConstType)]`; \
+        for other user-defined types, implement the trait with `impl_const!`"
+)]
+pub trait Const<T>: sealed::Sealed<T> {
     /// The constant value of type `T`.
     const VALUE: T;
+
+    /// Returns the constant value of type `T`.
+    ///
+    /// This is a method form of [`Const::VALUE`]. Since every use of an associated
+    /// constant produces a fresh value, `T` doesn't have to implement [`Copy`].
+    #[must_use]
+    fn value() -> T {
+        Self::VALUE
+    }
+}
+
+/// A trait whose types-implementors represent a constant value of the type [`ConstVal::Value`].
+///
+/// Unlike [`Const<T>`], the type of the constant is an associated type, so generic code
+/// can be written as `fn f<C: ConstVal>() -> C::Value { C::VALUE }` without carrying `T` around.
+///
+/// A blanket impl for all types implementing [`Const<T>`] is impossible because a type may
+/// implement [`Const<T>`] for several `T`. Instead, [`Const<Self::Value>`] is a supertrait,
+/// and the trait is implemented for the const types provided by this crate and by the derive macros.
+pub trait ConstVal: Const<Self::Value> {
+    /// The type of the constant.
+    type Value;
+}
+
+/// A marker of the types whose [`OptionallyConst<T>`] impl is written in terms of
+/// their [`Const<T>`] impl.
+///
+/// `OptionallyConst<T>` can't be implemented for all types implementing `Const<T>` because
+/// such an impl would conflict with the blanket impl for `T: Clone + Copy + PartialEq` in
+/// the absence of negative trait bounds. Instead, a type is opted into the "const" branch
+/// with a single [`impl_optionally_const_via_const!`] line, which implements both this marker
+/// and `OptionallyConst<T>`.
+///
+/// The trait is sealed, so `T: IsConst<U>` guarantees that
+/// [`OptionallyConst::MAYBE_CONST`] is `Some(Const::VALUE)`.
+pub trait IsConst<T>: Const<T> + OptionallyConst<T> + sealed::IsConstSealed<T> {}
+
+/// Returns the constant represented by the type `C`.
+///
+/// This is a free `const fn` form of [`Const::VALUE`], e.g.
+/// `const_value_of::<bool, ConstTypeBool<true>>()`.
+pub const fn const_value_of<T, C>() -> T
+where
+    T: Copy,
+    C: Const<T>,
+{
+    C::VALUE
+}
+
+/// Returns the value in `maybe` if it's `Some`, or `runtime` otherwise.
+///
+/// This is a `const` counterpart of [`Option::unwrap_or`] meant to be called as
+/// `pick(const { T::MAYBE_CONST }, runtime)`, which is what [`const_or!`] expands to.
+#[must_use]
+pub const fn pick<T: Copy>(maybe: Option<T>, runtime: T) -> T {
+    match maybe {
+        Some(value) => value,
+        None => runtime,
+    }
+}
+
+/// Calls `on_const` with the constant if the type `U` represents one, or
+/// `on_runtime` with the runtime value otherwise.
+///
+/// This encapsulates the common pattern of branching on [`OptionallyConst::MAYBE_CONST`]:
+///
+/// ```rust
+/// use optionally_const::{const_dispatch, const_type_instance, OptionallyConst};
+///
+/// fn describe<T: OptionallyConst<bool>>(flag: T) -> String {
+///     const_dispatch(
+///         flag,
+///         |flag| format!("flag is const: {flag}"),
+///         |flag| format!("flag is not const: {flag}"),
+///     )
+/// }
+///
+/// assert_eq!(describe(const_type_instance!(true)), "flag is const: true");
+/// assert_eq!(describe(false), "flag is not const: false");
+/// ```
+pub fn const_dispatch<T, U, R>(
+    carrier: U,
+    on_const: impl FnOnce(T) -> R,
+    on_runtime: impl FnOnce(T) -> R,
+) -> R
+where
+    U: OptionallyConst<T>,
+{
+    match U::MAYBE_CONST {
+        Some(value) => on_const(value),
+        None => on_runtime(carrier.into_value()),
+    }
+}
+
+/// An error returned by [`OptionallyConst::try_from_value_err`] when a value
+/// does not match the constant represented by the type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConstMismatch<T> {
+    /// The offending value.
+    pub value: T,
+    /// The constant represented by the type, if known.
+    pub expected: Option<T>,
+}
+
+impl<T: core::fmt::Display> core::fmt::Display for ConstMismatch<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match &self.expected {
+            Some(expected) => write!(f, "expected const {expected}, got {}", self.value),
+            None => write!(f, "the value {} does not match the type", self.value),
+        }
+    }
+}
+
+impl<T: core::fmt::Debug + core::fmt::Display> core::error::Error for ConstMismatch<T> {}
+
+/// An error returned when a string does not name an accepted variant of a fieldless enum.
+///
+/// It is returned by the [`FromStr`](core::str::FromStr) impls of the enums declared with
+/// [`fieldless_enum!`] and of the const types derived with the `from_str` option of
+/// `#[const_type(...)]`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseVariantError {
+    /// The names of the accepted variants.
+    pub expected: &'static [&'static str],
+}
+
+impl core::fmt::Display for ParseVariantError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self.expected {
+            [name] => write!(f, "expected `{name}`"),
+            names => {
+                f.write_str("expected one of ")?;
+                for (i, name) in names.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "`{name}`")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl core::error::Error for ParseVariantError {}
+
+// Provides `const fn value()` on the const types, which is usable in const contexts unlike
+// the trait items. The `OptionallyConst::into_value` impls of the const types are routed
+// through it.
+macro_rules! impl_value {
+    ($($carrier:ident<$param:ident: $param_ty:ty> => $ty:ty;)*) => {
+        $(
+            impl<const $param: $param_ty> $carrier<$param> {
+                /// Returns the constant represented by the const type.
+                ///
+                /// Unlike [`Const::value`], this function is `const`.
+                #[must_use]
+                pub const fn value() -> $ty {
+                    <Self as Const<$ty>>::VALUE
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(not(feature = "generic_const_type"))]
+impl_value! {
+    ConstTypeBool<VAL: bool> => bool;
 }
 
+#[cfg(not(feature = "generic_const_type"))]
+impl<const VAL: bool> sealed::Sealed<bool> for ConstTypeBool<VAL> {}
+
+#[cfg(not(feature = "generic_const_type"))]
 impl<const VAL: bool> Const<bool> for ConstTypeBool<VAL> {
     const VALUE: bool = VAL;
 }
 
+#[cfg(not(feature = "generic_const_type"))]
+impl<const VAL: bool> ConstVal for ConstTypeBool<VAL> {
+    type Value = bool;
+}
+
 // TODO: redefine the impls once negative trait bounds are available
 
 impl<T> OptionallyConst<T> for T
@@ -116,11 +872,12 @@ where
 //     }
 // }
 
+#[cfg(not(feature = "generic_const_type"))]
 impl<const VAL: bool> OptionallyConst<bool> for ConstTypeBool<VAL> {
     const MAYBE_CONST: Option<bool> = Some(VAL);
 
     fn into_value(self) -> bool {
-        VAL
+        Self::value()
     }
 
     fn try_from_value(value: bool) -> Result<Self, bool> {
@@ -132,154 +889,2891 @@ impl<const VAL: bool> OptionallyConst<bool> for ConstTypeBool<VAL> {
     }
 }
 
-/// Returns an instance of the type that represents the constant.
-///
-/// At the moment of writing, the macro cannot support user-defined types
-/// implementing the [`Const`] trait.
-///
-/// However, you still can construct instances of types that represent
-/// constant values of type `T` manually.
-#[macro_export]
-macro_rules! const_type_instance {
-    (true $(: bool)?) => {
-        $crate::hidden::ConstTypeBool::<true>
-    };
-    (false $(: bool)?) => {
-        $crate::hidden::ConstTypeBool::<false>
+impl<const VAL: bool> PartialEq<bool> for ConstTypeBool<VAL> {
+    fn eq(&self, other: &bool) -> bool {
+        *other == VAL
+    }
+}
+
+impl<const VAL: bool> PartialEq<ConstTypeBool<VAL>> for bool {
+    fn eq(&self, _other: &ConstTypeBool<VAL>) -> bool {
+        *self == VAL
+    }
+}
+
+impl<const VAL: bool> From<ConstTypeBool<VAL>> for bool {
+    fn from(_carrier: ConstTypeBool<VAL>) -> bool {
+        VAL
+    }
+}
+
+impl<const VAL: bool> TryFrom<bool> for ConstTypeBool<VAL> {
+    type Error = bool;
+
+    fn try_from(value: bool) -> Result<Self, bool> {
+        <Self as OptionallyConst<bool>>::try_from_value(value)
+    }
+}
+
+// Defines the numeric const types. The const types are declared in a private module
+// and re-exported from the `hidden` module, while the public type aliases with
+// the same names are declared in the crate root.
+macro_rules! impl_numeric_const_type {
+    ($($carrier:ident: $ty:ty;)*) => {
+        mod numeric_const_types {
+            $(
+                #[derive(Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+                pub struct $carrier<const VAL: $ty>;
+
+                impl<const VAL: $ty> $carrier<VAL> {
+                    /// Creates an instance of the const type.
+                    pub const fn new() -> Self {
+                        Self
+                    }
+                }
+            )*
+        }
+
+        $(
+            #[doc = concat!("A convenience type alias that represents a constant `", stringify!($ty), "` value.")]
+            pub type $carrier<const VAL: $ty> = hidden::$carrier<VAL>;
+
+            impl<const VAL: $ty> sealed::Sealed<$ty> for $carrier<VAL> {}
+
+            impl<const VAL: $ty> Const<$ty> for $carrier<VAL> {
+                const VALUE: $ty = VAL;
+            }
+
+            impl_value! {
+                $carrier<VAL: $ty> => $ty;
+            }
+
+            impl<const VAL: $ty> ConstVal for $carrier<VAL> {
+                type Value = $ty;
+            }
+
+            impl<const VAL: $ty> OptionallyConst<$ty> for $carrier<VAL> {
+                const MAYBE_CONST: Option<$ty> = Some(VAL);
+
+                fn into_value(self) -> $ty {
+                    Self::value()
+                }
+
+                fn try_from_value(value: $ty) -> Result<Self, $ty> {
+                    if value == VAL {
+                        Ok(crate::hidden::$carrier::<VAL>)
+                    } else {
+                        Err(value)
+                    }
+                }
+            }
+
+            impl<const VAL: $ty> From<$carrier<VAL>> for $ty {
+                fn from(_carrier: $carrier<VAL>) -> $ty {
+                    VAL
+                }
+            }
+        )*
     };
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+impl_numeric_const_type! {
+    ConstTypeU8: u8;
+    ConstTypeU16: u16;
+    ConstTypeU32: u32;
+    ConstTypeU64: u64;
+    ConstTypeU128: u128;
+    ConstTypeUsize: usize;
+    ConstTypeI8: i8;
+    ConstTypeI16: i16;
+    ConstTypeI32: i32;
+    ConstTypeI64: i64;
+    ConstTypeI128: i128;
+    ConstTypeIsize: isize;
+}
 
-    #[test]
-    fn test_const_type() {
-        let a: ConstTypeBool<true> = const_type_instance!(true);
-        let b: ConstTypeBool<false> = const_type_instance!(false);
+impl<const VAL: char> sealed::Sealed<char> for ConstTypeChar<VAL> {}
 
-        let a_value: bool = a.into_value();
-        let b_value: bool = b.into_value();
+impl<const VAL: char> Const<char> for ConstTypeChar<VAL> {
+    const VALUE: char = VAL;
+}
+
+impl<const VAL: char> OptionallyConst<char> for ConstTypeChar<VAL> {
+    const MAYBE_CONST: Option<char> = Some(VAL);
 
-        assert_eq!(a_value, true);
-        assert_eq!(b_value, false);
+    fn into_value(self) -> char {
+        Self::value()
     }
 
-    fn print_flag<T: OptionallyConst<bool>>(flag: T) {
-        if let Some(flag) = T::MAYBE_CONST {
-            println!("flag is const: {flag}");
+    fn try_from_value(value: char) -> Result<Self, char> {
+        if value == VAL {
+            Ok(crate::hidden::ConstTypeChar::<VAL>)
         } else {
-            let flag: bool = flag.into_value();
-            println!("flag is not const: {flag}");
-        };
+            Err(value)
+        }
     }
+}
 
-    #[derive(Clone, Copy, PartialEq)]
-    enum MyEnum {
-        A,
-        B,
-        C,
+impl<const VAL: char> From<ConstTypeChar<VAL>> for char {
+    fn from(_carrier: ConstTypeChar<VAL>) -> char {
+        VAL
     }
+}
 
-    impl std::fmt::Display for MyEnum {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            match self {
-                MyEnum::A => write!(f, "A"),
-                MyEnum::B => write!(f, "B"),
-                MyEnum::C => write!(f, "C"),
-            }
+impl<const VAL: i8> sealed::Sealed<core::cmp::Ordering> for ConstTypeOrdering<VAL> {}
+
+impl<const VAL: i8> Const<core::cmp::Ordering> for ConstTypeOrdering<VAL> {
+    const VALUE: core::cmp::Ordering = match VAL {
+        -1 => core::cmp::Ordering::Less,
+        0 => core::cmp::Ordering::Equal,
+        1 => core::cmp::Ordering::Greater,
+        _ => panic!("`ConstTypeOrdering` must be parameterized by -1, 0, or 1"),
+    };
+}
+
+impl<const VAL: i8> OptionallyConst<core::cmp::Ordering> for ConstTypeOrdering<VAL> {
+    const MAYBE_CONST: Option<core::cmp::Ordering> =
+        Some(<Self as Const<core::cmp::Ordering>>::VALUE);
+
+    fn into_value(self) -> core::cmp::Ordering {
+        Self::value()
+    }
+
+    fn try_from_value(value: core::cmp::Ordering) -> Result<Self, core::cmp::Ordering> {
+        if value == <Self as Const<core::cmp::Ordering>>::VALUE {
+            Ok(crate::hidden::ConstTypeOrdering::<VAL>)
+        } else {
+            Err(value)
         }
     }
+}
 
-    #[derive(Clone, Copy, PartialEq)]
-    struct MyEnumAConstType;
-    #[derive(Clone, Copy, PartialEq)]
-    struct MyEnumBConstType;
-    #[derive(Clone, Copy, PartialEq)]
-    struct MyEnumCConstType;
+// Implements the traits for the const types representing the non-zero integers.
+// The const types are parameterized by the primitive integer because the `NonZero*` types
+// can't be used as types of const generic parameters.
+macro_rules! impl_nonzero_const_type {
+    ($($carrier:ident, $prim:ty, $nonzero:ty;)*) => {
+        $(
+            impl<const VAL: $prim> sealed::Sealed<$nonzero> for $carrier<VAL> {}
 
-    // Ideally, OptionallyConst<T> should be implemented for
-    // all types that implement Const<T>.
-    //
-    // However, impl of OptionallyConst<T> for all `T` conflicts with the
-    // impl of OptionallyConst<T> for all `U: Const<T>` in the absence of
-    // negative trait bounds.
+            impl<const VAL: $prim> Const<$nonzero> for $carrier<VAL> {
+                const VALUE: $nonzero = match <$nonzero>::new(VAL) {
+                    Some(value) => value,
+                    None => panic!(concat!(
+                        "`",
+                        stringify!($carrier),
+                        "` must be parameterized by a non-zero value"
+                    )),
+                };
+            }
+
+            impl<const VAL: $prim> OptionallyConst<$nonzero> for $carrier<VAL> {
+                const MAYBE_CONST: Option<$nonzero> = Some(<Self as Const<$nonzero>>::VALUE);
+
+                fn into_value(self) -> $nonzero {
+                    Self::value()
+                }
+
+                fn try_from_value(value: $nonzero) -> Result<Self, $nonzero> {
+                    if value == <Self as Const<$nonzero>>::VALUE {
+                        Ok(crate::hidden::$carrier::<VAL>)
+                    } else {
+                        Err(value)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_nonzero_const_type! {
+    ConstTypeNonZeroU8, u8, core::num::NonZeroU8;
+    ConstTypeNonZeroU16, u16, core::num::NonZeroU16;
+    ConstTypeNonZeroU32, u32, core::num::NonZeroU32;
+    ConstTypeNonZeroU64, u64, core::num::NonZeroU64;
+    ConstTypeNonZeroUsize, usize, core::num::NonZeroUsize;
+}
+
+impl<const NANOS: u128> sealed::Sealed<core::time::Duration> for ConstTypeDurationNanos<NANOS> {}
+
+impl<const NANOS: u128> Const<core::time::Duration> for ConstTypeDurationNanos<NANOS> {
+    const VALUE: core::time::Duration = {
+        const NANOS_PER_SEC: u128 = 1_000_000_000;
+        let secs = NANOS / NANOS_PER_SEC;
+        if secs > u64::MAX as u128 {
+            panic!("`ConstTypeDurationNanos` must be parameterized by a representable duration");
+        }
+        core::time::Duration::new(secs as u64, (NANOS % NANOS_PER_SEC) as u32)
+    };
+}
+
+impl<const NANOS: u128> OptionallyConst<core::time::Duration> for ConstTypeDurationNanos<NANOS> {
+    const MAYBE_CONST: Option<core::time::Duration> =
+        Some(<Self as Const<core::time::Duration>>::VALUE);
+
+    fn into_value(self) -> core::time::Duration {
+        Self::value()
+    }
 
-    impl Const<MyEnum> for MyEnumAConstType {
-        const VALUE: MyEnum = MyEnum::A;
+    fn try_from_value(value: core::time::Duration) -> Result<Self, core::time::Duration> {
+        if value == <Self as Const<core::time::Duration>>::VALUE {
+            Ok(crate::hidden::ConstTypeDurationNanos::<NANOS>)
+        } else {
+            Err(value)
+        }
     }
+}
 
-    impl OptionallyConst<MyEnum> for MyEnumAConstType {
-        const MAYBE_CONST: Option<MyEnum> = Some(MyEnum::A);
+impl<const VAL: u32> sealed::Sealed<usize> for ConstTypeUsizeFromU32<VAL> {}
 
-        fn into_value(self) -> MyEnum {
-            MyEnum::A
+impl<const VAL: u32> Const<usize> for ConstTypeUsizeFromU32<VAL> {
+    const VALUE: usize = {
+        assert!(
+            VAL as usize as u32 == VAL,
+            "`ConstTypeUsizeFromU32` must be parameterized by a value that fits into a `usize`"
+        );
+        VAL as usize
+    };
+}
+
+impl<const VAL: u32> OptionallyConst<usize> for ConstTypeUsizeFromU32<VAL> {
+    const MAYBE_CONST: Option<usize> = Some(<Self as Const<usize>>::VALUE);
+
+    fn into_value(self) -> usize {
+        Self::value()
+    }
+
+    fn try_from_value(value: usize) -> Result<Self, usize> {
+        if value == <Self as Const<usize>>::VALUE {
+            Ok(crate::hidden::ConstTypeUsizeFromU32::<VAL>)
+        } else {
+            Err(value)
         }
+    }
+}
 
-        fn try_from_value(value: MyEnum) -> Result<Self, MyEnum> {
-            if matches!(value, MyEnum::A) {
-                Ok(MyEnumAConstType)
-            } else {
-                Err(value)
+impl_value! {
+    ConstTypeChar<VAL: char> => char;
+    ConstTypeOrdering<VAL: i8> => core::cmp::Ordering;
+    ConstTypeNonZeroU8<VAL: u8> => core::num::NonZeroU8;
+    ConstTypeNonZeroU16<VAL: u16> => core::num::NonZeroU16;
+    ConstTypeNonZeroU32<VAL: u32> => core::num::NonZeroU32;
+    ConstTypeNonZeroU64<VAL: u64> => core::num::NonZeroU64;
+    ConstTypeNonZeroUsize<VAL: usize> => core::num::NonZeroUsize;
+    ConstTypeDurationNanos<NANOS: u128> => core::time::Duration;
+    ConstTypeUsizeFromU32<VAL: u32> => usize;
+    ConstSeed<SEED: u64> => u64;
+}
+
+macro_rules! impl_const_val {
+    ($($carrier:ident<$param:ident: $param_ty:ty> => $ty:ty;)*) => {
+        $(
+            impl<const $param: $param_ty> ConstVal for $carrier<$param> {
+                type Value = $ty;
             }
+        )*
+    };
+}
+
+impl_const_val! {
+    ConstTypeChar<VAL: char> => char;
+    ConstTypeOrdering<VAL: i8> => core::cmp::Ordering;
+    ConstTypeNonZeroU8<VAL: u8> => core::num::NonZeroU8;
+    ConstTypeNonZeroU16<VAL: u16> => core::num::NonZeroU16;
+    ConstTypeNonZeroU32<VAL: u32> => core::num::NonZeroU32;
+    ConstTypeNonZeroU64<VAL: u64> => core::num::NonZeroU64;
+    ConstTypeNonZeroUsize<VAL: usize> => core::num::NonZeroUsize;
+    ConstTypeDurationNanos<NANOS: u128> => core::time::Duration;
+    ConstTypeUsizeFromU32<VAL: u32> => usize;
+    ConstSeed<SEED: u64> => u64;
+}
+
+impl sealed::Sealed<()> for ConstUnit {}
+
+impl Const<()> for ConstUnit {
+    const VALUE: () = ();
+}
+
+impl ConstVal for ConstUnit {
+    type Value = ();
+}
+
+impl OptionallyConst<()> for ConstUnit {
+    const MAYBE_CONST: Option<()> = Some(());
+
+    fn into_value(self) {}
+
+    fn try_from_value(_value: ()) -> Result<Self, ()> {
+        Ok(ConstUnit)
+    }
+}
+
+impl<const SEED: u64> sealed::Sealed<u64> for ConstSeed<SEED> {}
+
+impl<const SEED: u64> Const<u64> for ConstSeed<SEED> {
+    const VALUE: u64 = SEED;
+}
+
+impl<const SEED: u64> OptionallyConst<u64> for ConstSeed<SEED> {
+    const MAYBE_CONST: Option<u64> = Some(SEED);
+
+    fn into_value(self) -> u64 {
+        Self::value()
+    }
+
+    fn try_from_value(value: u64) -> Result<Self, u64> {
+        if value == SEED {
+            Ok(ConstSeed::<SEED>)
+        } else {
+            Err(value)
         }
     }
+}
+
+// A blanket `impl<T, U: OptionallyConst<T>> OptionallyConst<T> for &U` would conflict
+// with the blanket impl for `T: Clone + Copy + PartialEq` because `&U` is such a type.
+// Therefore, the impls for references are provided for each const type separately.
+//
+// Since const types are zero-sized, `try_from_value` can return a reference
+// to a promoted constant.
+macro_rules! impl_optionally_const_for_ref {
+    ($($carrier:ident<$param:ident: $param_ty:ty> => $ty:ty = $instance:expr;)*) => {
+        $(
+            impl<'a, const $param: $param_ty> OptionallyConst<$ty> for &'a $carrier<$param> {
+                const MAYBE_CONST: Option<$ty> = <$carrier<$param> as OptionallyConst<$ty>>::MAYBE_CONST;
+
+                fn into_value(self) -> $ty {
+                    <$carrier<$param> as OptionallyConst<$ty>>::into_value(*self)
+                }
+
+                fn try_from_value(value: $ty) -> Result<Self, $ty> {
+                    <$carrier<$param> as OptionallyConst<$ty>>::try_from_value(value)
+                        .map(|_| &const { $instance })
+                }
+            }
+        )*
+    };
+}
+
+impl_optionally_const_for_ref! {
+    ConstTypeBool<VAL: bool> => bool = crate::hidden::const_type_bool::<VAL>();
+    ConstTypeU8<VAL: u8> => u8 = crate::hidden::ConstTypeU8::<VAL>;
+    ConstTypeU16<VAL: u16> => u16 = crate::hidden::ConstTypeU16::<VAL>;
+    ConstTypeU32<VAL: u32> => u32 = crate::hidden::ConstTypeU32::<VAL>;
+    ConstTypeU64<VAL: u64> => u64 = crate::hidden::ConstTypeU64::<VAL>;
+    ConstTypeU128<VAL: u128> => u128 = crate::hidden::ConstTypeU128::<VAL>;
+    ConstTypeUsize<VAL: usize> => usize = crate::hidden::ConstTypeUsize::<VAL>;
+    ConstTypeI8<VAL: i8> => i8 = crate::hidden::ConstTypeI8::<VAL>;
+    ConstTypeI16<VAL: i16> => i16 = crate::hidden::ConstTypeI16::<VAL>;
+    ConstTypeI32<VAL: i32> => i32 = crate::hidden::ConstTypeI32::<VAL>;
+    ConstTypeI64<VAL: i64> => i64 = crate::hidden::ConstTypeI64::<VAL>;
+    ConstTypeI128<VAL: i128> => i128 = crate::hidden::ConstTypeI128::<VAL>;
+    ConstTypeIsize<VAL: isize> => isize = crate::hidden::ConstTypeIsize::<VAL>;
+    ConstTypeChar<VAL: char> => char = crate::hidden::ConstTypeChar::<VAL>;
+    ConstTypeOrdering<VAL: i8> => core::cmp::Ordering = crate::hidden::ConstTypeOrdering::<VAL>;
+    ConstTypeNonZeroU8<VAL: u8> => core::num::NonZeroU8 = crate::hidden::ConstTypeNonZeroU8::<VAL>;
+    ConstTypeNonZeroU16<VAL: u16> => core::num::NonZeroU16 = crate::hidden::ConstTypeNonZeroU16::<VAL>;
+    ConstTypeNonZeroU32<VAL: u32> => core::num::NonZeroU32 = crate::hidden::ConstTypeNonZeroU32::<VAL>;
+    ConstTypeNonZeroU64<VAL: u64> => core::num::NonZeroU64 = crate::hidden::ConstTypeNonZeroU64::<VAL>;
+    ConstTypeNonZeroUsize<VAL: usize> => core::num::NonZeroUsize = crate::hidden::ConstTypeNonZeroUsize::<VAL>;
+    ConstTypeDurationNanos<NANOS: u128> => core::time::Duration = crate::hidden::ConstTypeDurationNanos::<NANOS>;
+    ConstTypeUsizeFromU32<VAL: u32> => usize = crate::hidden::ConstTypeUsizeFromU32::<VAL>;
+    ConstSeed<SEED: u64> => u64 = ConstSeed::<SEED>;
+}
+
+// Same as above, `impl<T, U: Const<T>> OptionallyConst<T> for PhantomData<U>` would conflict
+// with the blanket impl because `PhantomData<U>` implements `OptionallyConst<PhantomData<U>>`.
+// The impls for `PhantomData` are provided for each const type separately too.
+macro_rules! impl_optionally_const_for_phantom_data {
+    ($($carrier:ident<$param:ident: $param_ty:ty> => $ty:ty;)*) => {
+        $(
+            impl<const $param: $param_ty> OptionallyConst<$ty> for core::marker::PhantomData<$carrier<$param>> {
+                const MAYBE_CONST: Option<$ty> = Some(<$carrier<$param> as Const<$ty>>::VALUE);
+
+                fn into_value(self) -> $ty {
+                    <$carrier<$param> as Const<$ty>>::VALUE
+                }
+
+                fn try_from_value(value: $ty) -> Result<Self, $ty> {
+                    <$carrier<$param> as OptionallyConst<$ty>>::try_from_value(value)
+                        .map(|_| core::marker::PhantomData)
+                }
+            }
+        )*
+    };
+}
+
+impl_optionally_const_for_phantom_data! {
+    ConstTypeBool<VAL: bool> => bool;
+    ConstTypeU8<VAL: u8> => u8;
+    ConstTypeU16<VAL: u16> => u16;
+    ConstTypeU32<VAL: u32> => u32;
+    ConstTypeU64<VAL: u64> => u64;
+    ConstTypeU128<VAL: u128> => u128;
+    ConstTypeUsize<VAL: usize> => usize;
+    ConstTypeI8<VAL: i8> => i8;
+    ConstTypeI16<VAL: i16> => i16;
+    ConstTypeI32<VAL: i32> => i32;
+    ConstTypeI64<VAL: i64> => i64;
+    ConstTypeI128<VAL: i128> => i128;
+    ConstTypeIsize<VAL: isize> => isize;
+    ConstTypeChar<VAL: char> => char;
+    ConstTypeOrdering<VAL: i8> => core::cmp::Ordering;
+    ConstTypeNonZeroU8<VAL: u8> => core::num::NonZeroU8;
+    ConstTypeNonZeroU16<VAL: u16> => core::num::NonZeroU16;
+    ConstTypeNonZeroU32<VAL: u32> => core::num::NonZeroU32;
+    ConstTypeNonZeroU64<VAL: u64> => core::num::NonZeroU64;
+    ConstTypeNonZeroUsize<VAL: usize> => core::num::NonZeroUsize;
+    ConstTypeDurationNanos<NANOS: u128> => core::time::Duration;
+    ConstTypeUsizeFromU32<VAL: u32> => usize;
+    ConstSeed<SEED: u64> => u64;
+}
+
+/// A wrapper that forces the runtime path in generic code.
+///
+/// [`OptionallyConst::MAYBE_CONST`] is always `None` for `NotConst<T>`, even if
+/// the wrapped value could have been represented by a [const type]. This is useful
+/// for benchmarking or testing the runtime branch of generic code.
+///
+/// [const type]: https://github.com/JohnScience/optionally_const/tree/main/optionally_const#const-type
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NotConst<T>(pub T);
+
+impl<T> OptionallyConst<T> for NotConst<T>
+where
+    T: Clone + Copy + PartialEq,
+{
+    const MAYBE_CONST: Option<T> = None;
+
+    fn into_value(self) -> T {
+        self.0
+    }
 
-    impl Const<MyEnum> for MyEnumBConstType {
-        const VALUE: MyEnum = MyEnum::B;
+    fn try_from_value(value: T) -> Result<Self, T> {
+        Ok(NotConst(value))
     }
+}
 
-    impl OptionallyConst<MyEnum> for MyEnumBConstType {
-        const MAYBE_CONST: Option<MyEnum> = Some(MyEnum::B);
+/// A pair of optionally constant values that is optionally constant itself.
+///
+/// [`OptionallyConst::MAYBE_CONST`] is `Some` only if both components represent constants.
+///
+/// `OptionallyConst<(T1, T2)>` can't be implemented for the tuple `(A, B)` directly because
+/// it would conflict with the blanket impl for `T: Clone + Copy + PartialEq`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pair<A, B>(pub A, pub B);
 
-        fn into_value(self) -> MyEnum {
-            MyEnum::B
+impl<T1, T2, A, B> OptionallyConst<(T1, T2)> for Pair<A, B>
+where
+    A: OptionallyConst<T1>,
+    B: OptionallyConst<T2>,
+    T1: Copy,
+    T2: Copy,
+{
+    const MAYBE_CONST: Option<(T1, T2)> = match (A::MAYBE_CONST, B::MAYBE_CONST) {
+        (Some(a), Some(b)) => Some((a, b)),
+        _ => None,
+    };
+
+    fn into_value(self) -> (T1, T2) {
+        (self.0.into_value(), self.1.into_value())
+    }
+
+    fn try_from_value(value: (T1, T2)) -> Result<Self, (T1, T2)> {
+        match (A::try_from_value(value.0), B::try_from_value(value.1)) {
+            (Ok(a), Ok(b)) => Ok(Pair(a, b)),
+            _ => Err(value),
         }
+    }
+}
 
-        fn try_from_value(value: MyEnum) -> Result<Self, MyEnum> {
-            if matches!(value, MyEnum::B) {
-                Ok(MyEnumBConstType)
-            } else {
-                Err(value)
-            }
+/// A holder of either a const type instance or a runtime value.
+///
+/// Unlike a generic `C: OptionallyConst<T>` parameter, `MaybeConst<C, T>` lets a struct field
+/// decide between the const and the runtime representation at runtime. Since the arm is
+/// not known at compile time, [`OptionallyConst::MAYBE_CONST`] is always `None` for
+/// `MaybeConst<C, T>`. Use [`MaybeConst::maybe_const`] to query the constant instead.
+///
+/// [`OptionallyConst::try_from_value`] prefers the `Const` arm and falls back to
+/// the `Dynamic` arm, so it never fails.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MaybeConst<C, T> {
+    /// A const type instance.
+    Const(C),
+    /// A runtime value.
+    Dynamic(T),
+}
+
+impl<C, T> MaybeConst<C, T>
+where
+    C: OptionallyConst<T>,
+{
+    /// Returns the constant represented by the `Const` arm, if any.
+    ///
+    /// This is [`OptionallyConst::MAYBE_CONST`] of `C` for the `Const` arm
+    /// and `None` for the `Dynamic` arm.
+    pub fn maybe_const(&self) -> Option<T> {
+        match self {
+            MaybeConst::Const(_) => C::MAYBE_CONST,
+            MaybeConst::Dynamic(_) => None,
         }
     }
+}
+
+impl<C, T> OptionallyConst<T> for MaybeConst<C, T>
+where
+    C: OptionallyConst<T>,
+    T: Clone + Copy + PartialEq,
+{
+    const MAYBE_CONST: Option<T> = None;
 
-    impl Const<MyEnum> for MyEnumCConstType {
-        const VALUE: MyEnum = MyEnum::C;
+    fn into_value(self) -> T {
+        match self {
+            MaybeConst::Const(carrier) => carrier.into_value(),
+            MaybeConst::Dynamic(value) => value,
+        }
     }
 
-    impl OptionallyConst<MyEnum> for MyEnumCConstType {
-        const MAYBE_CONST: Option<MyEnum> = Some(MyEnum::C);
+    fn try_from_value(value: T) -> Result<Self, T> {
+        Ok(C::try_from_value(value).map_or(MaybeConst::Dynamic(value), MaybeConst::Const))
+    }
+}
 
-        fn into_value(self) -> MyEnum {
-            MyEnum::C
+/// An extension trait with boolean operations on optionally constant `bool`s.
+///
+/// The trait is implemented for all types that implement [`OptionallyConst<bool>`].
+///
+/// The result of an operation is computed in an inline `const` block whenever it's determined
+/// by the constant operands alone, e.g. when both operands are constant or when one of the operands
+/// of [`and`](OptionallyConstBoolExt::and) is a constant `false`. In that case, the runtime
+/// operands are not even read. The result can't be exposed as an associated constant because
+/// it depends on the type of the other operand, and associated constants can't be generic.
+///
+/// # Example
+///
+/// ```rust
+/// use optionally_const::{const_type_instance, OptionallyConstBoolExt};
+///
+/// let enabled = const_type_instance!(true);
+/// assert!(enabled.and(true));
+/// assert!(!enabled.xor(const_type_instance!(true)));
+/// assert!(false.or(enabled));
+/// ```
+pub trait OptionallyConstBoolExt: OptionallyConst<bool> {
+    /// Returns the logical conjunction of the values represented by the instances.
+    #[must_use]
+    fn and<U: OptionallyConst<bool>>(self, other: U) -> bool {
+        let maybe_const: Option<bool> = const {
+            match (Self::MAYBE_CONST, U::MAYBE_CONST) {
+                (Some(false), _) | (_, Some(false)) => Some(false),
+                (Some(true), Some(true)) => Some(true),
+                _ => None,
+            }
+        };
+        match maybe_const {
+            Some(value) => value,
+            None => self.into_value() && other.into_value(),
         }
+    }
 
-        fn try_from_value(value: MyEnum) -> Result<Self, MyEnum> {
-            if matches!(value, MyEnum::C) {
-                Ok(MyEnumCConstType)
-            } else {
-                Err(value)
+    /// Returns the logical disjunction of the values represented by the instances.
+    #[must_use]
+    fn or<U: OptionallyConst<bool>>(self, other: U) -> bool {
+        let maybe_const: Option<bool> = const {
+            match (Self::MAYBE_CONST, U::MAYBE_CONST) {
+                (Some(true), _) | (_, Some(true)) => Some(true),
+                (Some(false), Some(false)) => Some(false),
+                _ => None,
             }
+        };
+        match maybe_const {
+            Some(value) => value,
+            None => self.into_value() || other.into_value(),
         }
     }
 
-    fn print_my_enum<T: OptionallyConst<MyEnum>>(value: T) {
-        if let Some(value) = T::MAYBE_CONST {
-            println!("value is const: {value}");
-        } else {
-            let value: MyEnum = value.into_value();
-            println!("value is not const: {value}");
+    /// Returns the exclusive disjunction of the values represented by the instances.
+    #[must_use]
+    fn xor<U: OptionallyConst<bool>>(self, other: U) -> bool {
+        let maybe_const: Option<bool> = const {
+            match (Self::MAYBE_CONST, U::MAYBE_CONST) {
+                (Some(a), Some(b)) => Some(a != b),
+                _ => None,
+            }
         };
+        match maybe_const {
+            Some(value) => value,
+            None => self.into_value() != other.into_value(),
+        }
     }
+}
 
-    #[test]
-    fn test_output_bool() {
-        print_flag(true);
-        print_flag(false);
-        print_flag(const_type_instance!(true));
-        print_flag(const_type_instance!(false));
+impl<T: OptionallyConst<bool>> OptionallyConstBoolExt for T {}
+
+/// A builder that stores an optionally constant value in its type.
+///
+/// This is a typestate helper returned by [`WithConst::with_const`]. Since the type of
+/// the stored value is a part of the type of the builder, the final `build` method
+/// can query [`OptionallyConst::MAYBE_CONST`] and specialize on const inputs.
+#[derive(Clone, Copy, PartialEq)]
+pub struct BuilderWith<B, C> {
+    /// The builder that the value was threaded through.
+    pub builder: B,
+    /// The optionally constant value.
+    pub value: C,
+}
+
+impl<B, C> BuilderWith<B, C> {
+    /// Returns the constant value stored in the builder, if any.
+    ///
+    /// This is a shorthand for `C::MAYBE_CONST`.
+    pub fn const_value<T>(&self) -> Option<T>
+    where
+        C: OptionallyConst<T>,
+    {
+        C::MAYBE_CONST
+    }
+
+    /// Splits the builder into the original builder and the stored value.
+    pub fn into_parts(self) -> (B, C) {
+        (self.builder, self.value)
+    }
+}
+
+/// A trait for threading an optionally constant value through a fluent builder
+/// without losing its const-ness.
+///
+/// The trait is implemented for all types.
+///
+/// The `with_const` method does not require `C: OptionallyConst<T>` because
+/// const types implement [`OptionallyConst`] both for the represented type and for themselves,
+/// which would make the `T` parameter ambiguous at the call site. Instead, the bound is expected
+/// on the `build` method of the builder.
+pub trait WithConst: Sized {
+    /// Stores the optionally constant value in the type of the builder.
+    fn with_const<C>(self, value: C) -> BuilderWith<Self, C> {
+        BuilderWith {
+            builder: self,
+            value,
+        }
+    }
+}
+
+impl<B> WithConst for B {}
+
+/// Returns an instance of the type that represents the constant.
+///
+/// Besides the `true` and `false` literals, the macro accepts integer literals.
+/// The carrier is selected by the suffix of the literal, e.g. `const_type_instance!(42u32)`
+/// expands to `ConstTypeU32::<42>`. An integer literal without a suffix
+/// produces a `ConstTypeI32` instance, mirroring the default integer type of Rust.
+/// The type can also be given as an annotation, e.g. `const_type_instance!(42: u32)`.
+///
+/// Character literals produce `ConstTypeChar` instances, e.g.
+/// `const_type_instance!('\n')` expands to `ConstTypeChar::<'\n'>`.
+///
+/// The `Less`, `Equal`, and `Greater` arms produce [`ConstTypeOrdering`] instances.
+///
+/// Any other input is treated as a constant boolean expression, e.g.
+/// `const_type_instance!(1 == 1)` or `const_type_instance!({ cfg!(test) })` expands to
+/// a [`ConstTypeBool`] instance parameterized by the value of the expression.
+///
+/// User-defined types implementing the [`Const`] trait can be constructed with the
+/// `@custom` arm, e.g. `const_type_instance!(@custom MyEnumAConstType)` expands to
+/// `MyEnumAConstType`. The type must be a unit struct.
+#[macro_export]
+macro_rules! const_type_instance {
+    (@custom $ty:path) => {
+        $ty
+    };
+    (Less) => {
+        $crate::hidden::ConstTypeOrdering::<-1>
+    };
+    (Equal) => {
+        $crate::hidden::ConstTypeOrdering::<0>
+    };
+    (Greater) => {
+        $crate::hidden::ConstTypeOrdering::<1>
+    };
+    (true $(: bool)?) => {
+        $crate::hidden::const_type_bool::<true>()
+    };
+    (false $(: bool)?) => {
+        $crate::hidden::const_type_bool::<false>()
+    };
+    ($val:literal : u8) => {
+        $crate::hidden::ConstTypeU8::<{ $val }>
+    };
+    ($val:literal : u16) => {
+        $crate::hidden::ConstTypeU16::<{ $val }>
+    };
+    ($val:literal : u32) => {
+        $crate::hidden::ConstTypeU32::<{ $val }>
+    };
+    ($val:literal : u64) => {
+        $crate::hidden::ConstTypeU64::<{ $val }>
+    };
+    ($val:literal : u128) => {
+        $crate::hidden::ConstTypeU128::<{ $val }>
+    };
+    ($val:literal : usize) => {
+        $crate::hidden::ConstTypeUsize::<{ $val }>
+    };
+    ($val:literal : i8) => {
+        $crate::hidden::ConstTypeI8::<{ $val }>
+    };
+    ($val:literal : i16) => {
+        $crate::hidden::ConstTypeI16::<{ $val }>
+    };
+    ($val:literal : i32) => {
+        $crate::hidden::ConstTypeI32::<{ $val }>
+    };
+    ($val:literal : i64) => {
+        $crate::hidden::ConstTypeI64::<{ $val }>
+    };
+    ($val:literal : i128) => {
+        $crate::hidden::ConstTypeI128::<{ $val }>
+    };
+    ($val:literal : isize) => {
+        $crate::hidden::ConstTypeIsize::<{ $val }>
+    };
+    ($val:literal : char) => {
+        $crate::hidden::ConstTypeChar::<{ $val }>
+    };
+    ($val:literal) => {
+        $crate::hidden::const_type_instance_literal!($crate, $val)
+    };
+    ($val:block) => {
+        $crate::hidden::const_type_bool::<$val>()
+    };
+    ($($expr:tt)+) => {
+        $crate::hidden::const_type_bool::<{ $($expr)+ }>()
+    };
+}
+
+/// Returns an instance of [`ConstTypeDurationNanos`] for a duration given as a value and a unit.
+///
+/// The supported units are `"ns"`, `"us"`, `"ms"`, and `"s"`. For example,
+/// `const_duration!(5, "ms")` expands to `ConstTypeDurationNanos::<{ 5 * 1_000_000 }>`.
+/// The value must be an integer literal or a constant expression of type `u128`.
+#[macro_export]
+macro_rules! const_duration {
+    ($val:expr, "ns") => {
+        $crate::hidden::ConstTypeDurationNanos::<{ $val }>
+    };
+    ($val:expr, "us") => {
+        $crate::hidden::ConstTypeDurationNanos::<{ $val * 1_000 }>
+    };
+    ($val:expr, "ms") => {
+        $crate::hidden::ConstTypeDurationNanos::<{ $val * 1_000_000 }>
+    };
+    ($val:expr, "s") => {
+        $crate::hidden::ConstTypeDurationNanos::<{ $val * 1_000_000_000 }>
+    };
+}
+
+/// Returns an instance of [`ConstTypeStr`] for a string literal.
+///
+/// For example, `const_str!("ok")` expands to `ConstTypeStr::<2, 0x6b6f>`. The string
+/// must be a constant expression of type `&str` and must be at most 16 bytes long.
+#[macro_export]
+macro_rules! const_str {
+    ($s:expr) => {
+        $crate::ConstTypeStr::<{ $s.len() }, { $crate::hidden::pack_str($s) }>
+    };
+}
+
+/// Returns an instance of [`ConstTypeBytes`] for a byte string literal.
+///
+/// For example, `const_bytes!(b"MZ")` expands to `ConstTypeBytes::<2, 0x5a4d>`. The bytes
+/// must be a constant expression of type `&[u8; N]` and must be at most 16 bytes long.
+#[macro_export]
+macro_rules! const_bytes {
+    ($b:expr) => {
+        $crate::ConstTypeBytes::<{ $b.len() }, { $crate::hidden::pack_bytes($b) }>
+    };
+}
+
+/// Dispatches on whether an [`OptionallyConst`] instance represents a constant.
+///
+/// `const_match!(carrier => |v| const_body, |x| runtime_body)` evaluates `const_body`
+/// with `v` bound to the constant if the type of `carrier` is a [const type], and
+/// `runtime_body` with `x` bound to the value of `carrier` otherwise.
+///
+/// Since [`OptionallyConst::MAYBE_CONST`] is an associated constant, the branch is
+/// selected per monomorphization and the other one is trivially dead code.
+///
+/// The type of the value can't always be inferred for concrete const types because they
+/// implement [`OptionallyConst`] both for the represented type and for themselves. The macro
+/// is meant for generic code with a `T: OptionallyConst<U>` bound.
+///
+/// # Example
+///
+/// ```rust
+/// use optionally_const::{const_match, const_type_instance, OptionallyConst};
+///
+/// fn describe<T: OptionallyConst<bool>>(flag: T) -> String {
+///     const_match!(flag => |v| format!("const {v}"), |x| format!("runtime {x}"))
+/// }
+///
+/// assert_eq!(describe(const_type_instance!(true)), "const true");
+/// assert_eq!(describe(false), "runtime false");
+/// ```
+///
+/// [const type]: https://github.com/JohnScience/optionally_const/tree/main/optionally_const#const-type
+#[macro_export]
+macro_rules! const_match {
+    ($carrier:expr => |$v:pat_param| $const_body:expr, |$x:pat_param| $runtime_body:expr $(,)?) => {
+        match $crate::hidden::split_const($carrier) {
+            ::core::result::Result::Ok($v) => $const_body,
+            ::core::result::Result::Err($x) => $runtime_body,
+        }
+    };
+}
+
+/// Selects a code path depending on whether a type represents a constant.
+///
+/// `const_if!(T, |v| optimized, fallback)` evaluates `optimized` with `v` bound to
+/// `T::MAYBE_CONST` if it is `Some`, and `fallback` otherwise. Unlike [`const_match!`],
+/// the macro inspects the type rather than an instance, so the fallback doesn't receive
+/// the runtime value.
+///
+/// Since [`OptionallyConst::MAYBE_CONST`] is an associated constant, the branch is
+/// selected per monomorphization. `T::MAYBE_CONST` is placed in a `const { }` block,
+/// so it's guaranteed to be evaluated at compile time. The macro is meant for generic code
+/// with a `T: OptionallyConst<U>` bound.
+///
+/// # Example
+///
+/// ```rust
+/// use optionally_const::{const_if, const_type_instance, OptionallyConst};
+///
+/// fn scale<T: OptionallyConst<u32>>(factor: T, x: u32) -> u32 {
+///     const_if!(T, |f| x * f, x * factor.into_value())
+/// }
+///
+/// assert_eq!(scale(const_type_instance!(3: u32), 2), 6);
+/// assert_eq!(scale(3u32, 2), 6);
+/// ```
+#[macro_export]
+macro_rules! const_if {
+    ($ty:ty, |$v:pat_param| $optimized:expr, $fallback:expr $(,)?) => {
+        match const { <$ty>::MAYBE_CONST } {
+            ::core::option::Option::Some($v) => $optimized,
+            ::core::option::Option::None => $fallback,
+        }
+    };
+}
+
+/// Returns `T::MAYBE_CONST` if the type `T` represents a constant, or the runtime value otherwise.
+///
+/// `const_or!(T, runtime)` expands to `pick(const { T::MAYBE_CONST }, runtime)`, see [`pick`].
+/// The `const { }` block forces `T::MAYBE_CONST` to be evaluated at compile time for every
+/// monomorphization, so the selection doesn't depend on any runtime comparison.
+///
+/// With optimizations enabled, the untaken branch is removed. With `-C opt-level=0`, no dead code
+/// elimination is performed, so the selection is still compiled as a `match`, but it matches on
+/// a constant. The `runtime` expression is evaluated in either case, so it should be cheap,
+/// e.g. a call to [`OptionallyConst::into_value`].
+///
+/// # Example
+///
+/// ```rust
+/// use optionally_const::{const_or, const_type_instance, OptionallyConst};
+///
+/// fn shift<T: OptionallyConst<u32>>(amount: T, x: u32) -> u32 {
+///     x << const_or!(T, amount.into_value())
+/// }
+///
+/// assert_eq!(shift(const_type_instance!(3: u32), 1), 8);
+/// assert_eq!(shift(2u32, 1), 4);
+/// ```
+#[macro_export]
+macro_rules! const_or {
+    ($ty:ty, $runtime:expr $(,)?) => {
+        $crate::pick(const { <$ty>::MAYBE_CONST }, $runtime)
+    };
+}
+
+/// Implements the sealed [`Const<T>`] trait for a user-defined type.
+///
+/// `impl_const!(Type: T = value)` implements `Const<T>` for `Type` with `value` as
+/// [`Const::VALUE`]. Several impls can be separated with semicolons. The macro doesn't
+/// implement [`OptionallyConst<T>`]; use [`impl_optionally_const_via_const!`] for that.
+///
+/// # Example
+///
+/// ```rust
+/// use optionally_const::{impl_const, Const};
+///
+/// struct Answer;
+/// struct Greeting;
+///
+/// impl_const! {
+///     Answer: u32 = 42;
+///     Greeting: &'static str = "hello";
+/// }
+///
+/// assert_eq!(<Answer as Const<u32>>::VALUE, 42);
+/// assert_eq!(<Greeting as Const<&'static str>>::VALUE, "hello");
+/// ```
+#[macro_export]
+macro_rules! impl_const {
+    ($($ty:ty: $value_ty:ty = $value:expr);+ $(;)?) => {
+        $(
+            impl $crate::sealed::Sealed<$value_ty> for $ty {}
+
+            impl $crate::Const<$value_ty> for $ty {
+                const VALUE: $value_ty = $value;
+            }
+        )+
+    };
+}
+
+/// Implements [`OptionallyConst<T>`] and the [`IsConst<T>`] marker for a type
+/// that implements [`Const<T>`].
+///
+/// `impl_optionally_const_via_const!(Type: T => constructor)` writes the impl in terms of
+/// [`Const::VALUE`]. The `constructor` expression creates the instance returned by
+/// [`OptionallyConst::try_from_value`]. For a unit struct, it can be omitted, e.g.
+/// `impl_optionally_const_via_const!(Type: T)`. Several impls can be separated with commas
+/// or semicolons.
+///
+/// # Example
+///
+/// ```rust
+/// use core::marker::PhantomData;
+///
+/// use optionally_const::{impl_const, impl_optionally_const_via_const, IsConst, OptionallyConst};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Mode {
+///     Fast,
+///     Safe,
+/// }
+///
+/// #[derive(Clone, Copy, PartialEq)]
+/// struct FastMode;
+///
+/// #[derive(Clone, Copy, PartialEq)]
+/// struct Tagged<Tag>(PhantomData<Tag>);
+///
+/// impl_const! {
+///     FastMode: Mode = Mode::Fast;
+///     Tagged<u8>: Mode = Mode::Safe;
+/// }
+///
+/// impl_optionally_const_via_const! {
+///     FastMode: Mode;
+///     Tagged<u8>: Mode => Tagged(PhantomData);
+/// }
+///
+/// fn is_const<C: IsConst<Mode>>(_carrier: C) -> bool {
+///     true
+/// }
+///
+/// assert_eq!(<FastMode as OptionallyConst<Mode>>::MAYBE_CONST, Some(Mode::Fast));
+/// assert!(FastMode::try_from_value(Mode::Safe).is_err());
+/// assert!(Tagged::<u8>::try_from_value(Mode::Safe).is_ok());
+/// assert!(is_const(FastMode));
+/// ```
+#[macro_export]
+macro_rules! impl_optionally_const_via_const {
+    ($($ty:ty: $value_ty:ty $(=> $ctor:expr)?),+ $(,)?) => {
+        $(
+            $crate::impl_optionally_const_via_const!(@impl $ty: $value_ty => [$($ctor)?]);
+        )+
+    };
+    ($($ty:ty: $value_ty:ty $(=> $ctor:expr)?);+ $(;)?) => {
+        $(
+            $crate::impl_optionally_const_via_const!(@impl $ty: $value_ty => [$($ctor)?]);
+        )+
+    };
+    // A unit struct is constructed with `Self`.
+    (@impl $ty:ty: $value_ty:ty => []) => {
+        $crate::impl_optionally_const_via_const!(@impl $ty: $value_ty => [Self]);
+    };
+    (@impl $ty:ty: $value_ty:ty => [$ctor:expr]) => {
+        impl $crate::sealed::IsConstSealed<$value_ty> for $ty {}
+
+        impl $crate::IsConst<$value_ty> for $ty {}
+
+        impl $crate::OptionallyConst<$value_ty> for $ty {
+            const MAYBE_CONST: ::core::option::Option<$value_ty> =
+                ::core::option::Option::Some(<$ty as $crate::Const<$value_ty>>::VALUE);
+
+            fn into_value(self) -> $value_ty {
+                <$ty as $crate::Const<$value_ty>>::VALUE
+            }
+
+            fn try_from_value(value: $value_ty) -> ::core::result::Result<Self, $value_ty> {
+                if value == <$ty as $crate::Const<$value_ty>>::VALUE {
+                    ::core::result::Result::Ok($ctor)
+                } else {
+                    ::core::result::Result::Err(value)
+                }
+            }
+        }
+    };
+}
+
+/// Names the [const type] of a fieldless enum variant.
+///
+/// `const_carrier_for!(ConstTypeName, Enum::Variant)` expands to
+/// `ConstTypeName::<{ Enum::Variant as usize }>`, so it can be used both in type
+/// and in expression positions.
+///
+/// # Example
+///
+/// ```rust
+/// use optionally_const::const_carrier_for;
+///
+/// #[derive(Clone, Copy, PartialEq)]
+/// enum Direction {
+///     Left,
+///     Right,
+/// }
+///
+/// // Normally, the const type is generated with `#[derive(FieldlessEnumConstType)]`.
+/// #[derive(Clone, Copy, PartialEq)]
+/// struct DirectionConstType<const DISCRIMINANT: usize>;
+///
+/// let right: const_carrier_for!(DirectionConstType, Direction::Right) =
+///     const_carrier_for!(DirectionConstType, Direction::Right);
+/// assert!(right == DirectionConstType::<{ Direction::Right as usize }>);
+/// ```
+///
+/// [const type]: https://github.com/JohnScience/optionally_const/tree/main/optionally_const#const-type
+#[macro_export]
+macro_rules! const_carrier_for {
+    ($($const_type:ident)::+, $variant:path $(,)?) => {
+        $($const_type)::+::<{ $variant as usize }>
+    };
+}
+
+/// Asserts at compile time that a type represents a constant.
+///
+/// `assert_const!(T)` expands to a `const` block that asserts that `T::MAYBE_CONST` is `Some`.
+/// This form is meant for generic code with a `T: OptionallyConst<U>` bound. For concrete const
+/// types, which implement [`OptionallyConst`] both for the represented type and for themselves,
+/// the represented type has to be specified, e.g. `assert_const!(ConstTypeBool<true>, bool)`.
+///
+/// The macro produces a compile error for plain runtime types, such as `bool`. In generic code,
+/// the error is reported when the function is instantiated with such a type.
+///
+/// # Example
+///
+/// ```rust
+/// use optionally_const::{assert_const, ConstTypeBool, OptionallyConst};
+///
+/// fn const_flag<T: OptionallyConst<bool>>(flag: T) -> bool {
+///     assert_const!(T);
+///     flag.into_value()
+/// }
+///
+/// assert_const!(ConstTypeBool<true>, bool);
+/// assert!(const_flag(ConstTypeBool::<true>::new()));
+/// ```
+///
+/// ```rust,compile_fail
+/// use optionally_const::assert_const;
+///
+/// assert_const!(bool, bool);
+/// ```
+#[macro_export]
+macro_rules! assert_const {
+    ($ty:ty) => {
+        const {
+            ::core::assert!(
+                <$ty>::MAYBE_CONST.is_some(),
+                "the type is expected to represent a constant"
+            )
+        }
+    };
+    ($ty:ty, $value_ty:ty) => {
+        const {
+            ::core::assert!(
+                <$ty as $crate::OptionallyConst<$value_ty>>::MAYBE_CONST.is_some(),
+                "the type is expected to represent a constant"
+            )
+        }
+    };
+}
+
+/// Declares unit structs that represent constants of a given type together with
+/// their [`Const`] and [`OptionallyConst`] implementations.
+///
+/// `const_carriers! { $vis Type { value => Carrier, ... } }` expands to a `$vis struct Carrier;`
+/// for every arm. The type must implement [`Clone`], [`Copy`], and [`PartialEq`].
+///
+/// The value of an arm is either
+///
+/// * an identifier, which names an associated item of the type, e.g. an enum variant,
+/// * a literal, e.g. `1`, `'x'`, or `true`,
+/// * a block with an arbitrary constant expression, e.g. `{ -1 }`.
+///
+/// The generated carriers derive [`Clone`], [`Copy`], [`PartialEq`], [`Eq`], and [`Hash`].
+///
+/// # Example
+///
+/// ```rust
+/// use optionally_const::{const_carriers, Const, OptionallyConst};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Direction {
+///     Up,
+///     Down,
+/// }
+///
+/// const_carriers! {
+///     Direction {
+///         Up => UpConstType,
+///         Down => DownConstType,
+///     }
+/// }
+///
+/// const_carriers! {
+///     pub u8 {
+///         1 => OneConstType,
+///         { u8::MAX } => MaxConstType,
+///     }
+/// }
+///
+/// assert_eq!(<UpConstType as Const<Direction>>::VALUE, Direction::Up);
+/// assert_eq!(<MaxConstType as OptionallyConst<u8>>::MAYBE_CONST, Some(255));
+/// ```
+///
+/// [`Hash`]: core::hash::Hash
+#[macro_export]
+macro_rules! const_carriers {
+    ($vis:vis $ty:ty { $($value:tt => $carrier:ident),* $(,)? }) => {
+        $(
+            $crate::const_carriers!(@carrier $vis $ty, $value => $carrier);
+        )*
+    };
+    (@carrier $vis:vis $ty:ty, $value:ident => $carrier:ident) => {
+        $crate::const_carriers!(@impl $vis $ty, <$ty>::$value => $carrier);
+    };
+    (@carrier $vis:vis $ty:ty, $value:literal => $carrier:ident) => {
+        $crate::const_carriers!(@impl $vis $ty, $value => $carrier);
+    };
+    (@carrier $vis:vis $ty:ty, $value:block => $carrier:ident) => {
+        $crate::const_carriers!(@impl $vis $ty, $value => $carrier);
+    };
+    (@impl $vis:vis $ty:ty, $value:expr => $carrier:ident) => {
+        #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+        $vis struct $carrier;
+
+        impl $crate::sealed::Sealed<$ty> for $carrier {}
+
+        impl $crate::Const<$ty> for $carrier {
+            const VALUE: $ty = $value;
+        }
+
+        impl $crate::ConstVal for $carrier {
+            type Value = $ty;
+        }
+
+        impl $carrier {
+            /// Returns the constant represented by the type.
+            #[must_use]
+            $vis const fn value() -> $ty {
+                <Self as $crate::Const<$ty>>::VALUE
+            }
+        }
+
+        impl $crate::OptionallyConst<$ty> for $carrier {
+            const MAYBE_CONST: ::core::option::Option<$ty> =
+                ::core::option::Option::Some(<Self as $crate::Const<$ty>>::VALUE);
+
+            fn into_value(self) -> $ty {
+                Self::value()
+            }
+
+            fn try_from_value(value: $ty) -> ::core::result::Result<Self, $ty> {
+                if value == <Self as $crate::Const<$ty>>::VALUE {
+                    ::core::result::Result::Ok($carrier)
+                } else {
+                    ::core::result::Result::Err(value)
+                }
+            }
+        }
+    };
+}
+
+/// Declares a [fieldless enum] together with its [const type] in one invocation.
+///
+/// This is a higher-level front-end over the [`FieldlessEnumConstType`] derive macro.
+/// Besides deriving the const type, the macro
+///
+/// * derives [`Clone`], [`Copy`], [`PartialEq`], and [`Debug`] on the enum,
+/// * implements [`Display`] and [`FromStr`] keyed by the variant names,
+/// * implements [`TryFrom<usize>`] keyed by the variant discriminants,
+/// * defines an associated `ALL` array with every variant in declaration order.
+///
+/// # Example
+///
+/// ```rust
+/// use optionally_const::{fieldless_enum, OptionallyConst};
+///
+/// fieldless_enum!(pub Letter { A, B, C } as LetterConstType);
+///
+/// assert_eq!("B".parse::<Letter>(), Ok(Letter::B));
+/// assert_eq!(Letter::B.to_string(), "B");
+/// assert_eq!(Letter::try_from(2usize), Ok(Letter::C));
+/// assert_eq!(Letter::ALL, [Letter::A, Letter::B, Letter::C]);
+/// assert_eq!(
+///     LetterConstType::<{ Letter::A as usize }>::MAYBE_CONST,
+///     Some(Letter::A),
+/// );
+/// ```
+///
+/// [fieldless enum]: https://doc.rust-lang.org/reference/items/enumerations.html#r-items.enum.fieldless
+/// [const type]: https://github.com/JohnScience/optionally_const/tree/main/optionally_const#const-type
+/// [`Display`]: core::fmt::Display
+/// [`FromStr`]: core::str::FromStr
+#[cfg(feature = "derive")]
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+#[macro_export]
+macro_rules! fieldless_enum {
+    (
+        $(#[$attr:meta])*
+        $vis:vis $ident:ident { $($variant:ident),* $(,)? } as $const_type_ident:ident
+    ) => {
+        $(#[$attr])*
+        #[derive($crate::FieldlessEnumConstType, Clone, Copy, PartialEq, Debug)]
+        #[const_type(
+            #[derive(Clone, Copy, PartialEq)]
+            $const_type_ident
+        )]
+        $vis enum $ident {
+            $($variant),*
+        }
+
+        impl $ident {
+            /// All variants of the enum in declaration order.
+            $vis const ALL: [Self; [$(stringify!($variant)),*].len()] = [$(Self::$variant),*];
+        }
+
+        impl ::core::fmt::Display for $ident {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    $(Self::$variant => f.write_str(stringify!($variant)),)*
+                }
+            }
+        }
+
+        impl ::core::str::FromStr for $ident {
+            type Err = $crate::ParseVariantError;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                match s {
+                    $(stringify!($variant) => Ok(Self::$variant),)*
+                    _ => Err($crate::ParseVariantError {
+                        expected: &[$(stringify!($variant)),*],
+                    }),
+                }
+            }
+        }
+
+        impl ::core::convert::TryFrom<usize> for $ident {
+            type Error = usize;
+
+            fn try_from(discriminant: usize) -> ::core::result::Result<Self, usize> {
+                Self::from_discriminant(discriminant).ok_or(discriminant)
+            }
+        }
+    };
+}
+
+// Used by the `FieldlessEnumConstType` derive macro.
+//
+// The `for<'a>` in the where clauses defers the check of the bound on the enum
+// to the use site. This way, the derive keeps working for enums that don't implement
+// `Serialize` or `Deserialize` even when the `serde` feature is enabled.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fieldless_enum_serde_impls {
+    ($ident:ident, $const_type_ident:ident, [$($(#[$cfg:meta])* $variant:ident),*], where $where:tt) => {
+        $(
+            $crate::__fieldless_enum_serde_impls! {
+                @variant $ident, $const_type_ident, $(#[$cfg])* $variant, where $where
+            }
+        )*
+    };
+    (@variant $ident:ident, $const_type_ident:ident, $(#[$cfg:meta])* $variant:ident, where [$($where:tt)*]) => {
+        $(#[$cfg])*
+        impl $crate::hidden::serde::Serialize for $const_type_ident<{ $ident::$variant as usize }>
+        where
+            for<'a> $ident: $crate::hidden::serde::Serialize,
+            $($where)*
+        {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::hidden::serde::Serializer,
+            {
+                $crate::hidden::serde::Serialize::serialize(&$ident::$variant, serializer)
+            }
+        }
+
+        $(#[$cfg])*
+        impl<'de> $crate::hidden::serde::Deserialize<'de> for $const_type_ident<{ $ident::$variant as usize }>
+        where
+            for<'a> $ident: $crate::hidden::serde::Deserialize<'de>,
+            $($where)*
+        {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::hidden::serde::Deserializer<'de>,
+            {
+                let value: $ident = $crate::hidden::serde::Deserialize::deserialize(deserializer)?;
+                if matches!(value, $ident::$variant) {
+                    Ok($const_type_ident)
+                } else {
+                    Err(<D::Error as $crate::hidden::serde::de::Error>::custom(concat!(
+                        "expected the `",
+                        stringify!($ident),
+                        "::",
+                        stringify!($variant),
+                        "` variant"
+                    )))
+                }
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fieldless_enum_serde_impls {
+    ($($tt:tt)*) => {};
+}
+
+// Used by the `FieldlessEnumConstType` derive macro.
+#[cfg(feature = "defmt")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fieldless_enum_defmt_impls {
+    ($ident:ident, $const_type_ident:ident, [$($(#[$cfg:meta])* $variant:ident),*], where $where:tt) => {
+        $(
+            $crate::__fieldless_enum_defmt_impls! {
+                @variant $ident, $const_type_ident, $(#[$cfg])* $variant, where $where
+            }
+        )*
+    };
+    (@variant $ident:ident, $const_type_ident:ident, $(#[$cfg:meta])* $variant:ident, where [$($where:tt)*]) => {
+        $(#[$cfg])*
+        impl $crate::hidden::defmt::Format for $const_type_ident<{ $ident::$variant as usize }>
+        where
+            $($where)*
+        {
+            fn format(&self, f: $crate::hidden::defmt::Formatter<'_>) {
+                $crate::hidden::defmt::Format::format(stringify!($variant), f)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "defmt"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fieldless_enum_defmt_impls {
+    ($($tt:tt)*) => {};
+}
+
+// Used by the `FieldlessEnumConstType` derive macro.
+//
+// SAFETY: the generated const types are zero-sized unit structs, so they have
+// no padding and the only bit pattern they can be represented with is valid.
+// The impls are restricted to the parameterizations that represent the variants of the enum.
+#[cfg(feature = "bytemuck")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fieldless_enum_bytemuck_impls {
+    ($ident:ident, $const_type_ident:ident, where [$($where:tt)*]) => {
+        unsafe impl<const DISCRIMINANT: usize> $crate::hidden::bytemuck::Zeroable
+            for $const_type_ident<DISCRIMINANT>
+        where
+            $ident: $crate::hidden::ValidDiscriminant<DISCRIMINANT>,
+            $($where)*
+        {
+        }
+
+        unsafe impl<const DISCRIMINANT: usize> $crate::hidden::bytemuck::Pod
+            for $const_type_ident<DISCRIMINANT>
+        where
+            $ident: $crate::hidden::ValidDiscriminant<DISCRIMINANT>,
+            $($where)*
+        {
+        }
+    };
+}
+
+#[cfg(not(feature = "bytemuck"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fieldless_enum_bytemuck_impls {
+    ($($tt:tt)*) => {};
+}
+
+// Used by the `FieldlessEnumConstType` derive macro.
+//
+// Each generated const type has exactly one instance, so no entropy is consumed.
+// The impl is restricted to the parameterizations that represent the variants of the enum.
+#[cfg(feature = "arbitrary")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fieldless_enum_arbitrary_impls {
+    ($ident:ident, $const_type_ident:ident, where [$($where:tt)*]) => {
+        impl<'a, const DISCRIMINANT: usize> $crate::hidden::arbitrary::Arbitrary<'a>
+            for $const_type_ident<DISCRIMINANT>
+        where
+            $ident: $crate::hidden::ValidDiscriminant<DISCRIMINANT>,
+            $($where)*
+        {
+            fn arbitrary(
+                _u: &mut $crate::hidden::arbitrary::Unstructured<'a>,
+            ) -> $crate::hidden::arbitrary::Result<Self> {
+                Ok($const_type_ident)
+            }
+
+            fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+                (0, Some(0))
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "arbitrary"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fieldless_enum_arbitrary_impls {
+    ($($tt:tt)*) => {};
+}
+
+// Used by the `FieldlessEnumConstType` derive macro.
+//
+// The const types are single-valued, so the strategy generates the variants of the enum.
+// As with serde, the `for<'a>` bound makes the `Debug` requirement of proptest
+// checked at the use site rather than at the definition.
+#[cfg(feature = "proptest")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fieldless_enum_proptest_impls {
+    ($vis:vis $ident:ident, [$($(#[$cfg:meta])* $variant:ident),*]) => {
+        impl $ident {
+            #[doc = concat!(
+                "Returns a [proptest] strategy that generates the variants of [`",
+                stringify!($ident),
+                "`].\n\n\
+                This is a code-generated function that was derived with the \
+                [`#[derive(FieldlessEnumConstType)]`](::optionally_const::FieldlessEnumConstType) \
+                derive macro.\n\n\
+                [proptest]: https://docs.rs/proptest"
+            )]
+            $vis fn strategy() -> impl $crate::hidden::proptest::strategy::Strategy<Value = $ident>
+            where
+                for<'a> $ident: ::core::fmt::Debug,
+            {
+                const VARIANTS: &[$ident] = &[$($(#[$cfg])* $ident::$variant),*];
+                $crate::hidden::proptest::sample::select(VARIANTS)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "proptest"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __fieldless_enum_proptest_impls {
+    ($($tt:tt)*) => {};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_const_type() {
+        let a: ConstTypeBool<true> = const_type_instance!(true);
+        let b: ConstTypeBool<false> = const_type_instance!(false);
+
+        let a_value: bool = a.into_value();
+        let b_value: bool = b.into_value();
+
+        assert!(a_value);
+        assert!(!b_value);
+    }
+
+    #[test]
+    fn test_numeric_const_type_instance() {
+        let a: ConstTypeU8<255> = const_type_instance!(255: u8);
+        let b: ConstTypeU32<42> = const_type_instance!(42u32: u32);
+        let c: ConstTypeUsize<5> = const_type_instance!(5: usize);
+        let d: ConstTypeI32<-7> = const_type_instance!(-7: i32);
+        let e: ConstTypeI64<{ i64::MIN }> = const_type_instance!(-9223372036854775808: i64);
+        let f: ConstTypeI32<3> = const_type_instance!(3);
+
+        let a_value: u8 = a.into_value();
+        let b_value: u32 = b.into_value();
+        let c_value: usize = c.into_value();
+        let d_value: i32 = d.into_value();
+        let e_value: i64 = e.into_value();
+        let f_value: i32 = f.into_value();
+
+        assert_eq!(a_value, 255);
+        assert_eq!(b_value, 42);
+        assert_eq!(c_value, 5);
+        assert_eq!(d_value, -7);
+        assert_eq!(e_value, i64::MIN);
+        assert_eq!(f_value, 3);
+    }
+
+    #[test]
+    fn test_suffixed_const_type_instance() {
+        let a: ConstTypeU32<42> = const_type_instance!(42u32);
+        let b: ConstTypeUsize<5> = const_type_instance!(5usize);
+        let c: ConstTypeI32<7> = const_type_instance!(7);
+        let d: ConstTypeI8<-128> = const_type_instance!(-128i8);
+        let e: ConstTypeU64<0xff> = const_type_instance!(0xff_u64);
+
+        let a_value: u32 = a.into_value();
+        let b_value: usize = b.into_value();
+        let c_value: i32 = c.into_value();
+        let d_value: i8 = d.into_value();
+        let e_value: u64 = e.into_value();
+
+        assert_eq!(a_value, 42);
+        assert_eq!(b_value, 5);
+        assert_eq!(c_value, 7);
+        assert_eq!(d_value, -128);
+        assert_eq!(e_value, 0xff);
+    }
+
+    #[test]
+    fn test_cmp_value() {
+        use core::cmp::Ordering;
+
+        let three = const_type_instance!(3: u32);
+        assert_eq!(three.cmp_value(2), Ordering::Greater);
+        assert_eq!(three.cmp_value(3), Ordering::Equal);
+        assert_eq!(three.cmp_value(5), Ordering::Less);
+        assert_eq!(7u32.cmp_value(3), Ordering::Greater);
+
+        // The carriers implement `Ord`, so they can be used as keys of ordered collections.
+        let carriers: std::collections::BTreeSet<ConstTypeU32<3>> = [three, three].into();
+        assert_eq!(carriers.len(), 1);
+    }
+
+    #[test]
+    fn test_numeric_try_from_value() {
+        assert!(ConstTypeU32::<42>::try_from_value(42).is_ok());
+        assert_eq!(ConstTypeU32::<42>::try_from_value(43).err(), Some(43));
+    }
+
+    #[test]
+    fn test_not_const() {
+        assert_eq!(<NotConst<bool> as OptionallyConst<bool>>::MAYBE_CONST, None);
+
+        let value: bool = NotConst(true).into_value();
+        assert!(value);
+
+        assert_eq!(NotConst::<u8>::try_from_value(3), Ok(NotConst(3)));
+
+        print_flag(NotConst(true));
+    }
+
+    #[test]
+    fn test_char_const_type_instance() {
+        let a: ConstTypeChar<'x'> = const_type_instance!('x');
+        let b: ConstTypeChar<'\n'> = const_type_instance!('\n');
+        let c: ConstTypeChar<'\''> = const_type_instance!('\'');
+
+        let a_value: char = a.into_value();
+        let b_value: char = b.into_value();
+        let c_value: char = c.into_value();
+
+        assert_eq!(a_value, 'x');
+        assert_eq!(b_value, '\n');
+        assert_eq!(c_value, '\'');
+        assert_eq!(ConstTypeChar::<'\n'>::try_from_value('\r').err(), Some('\r'));
+    }
+
+    fn hash_str<S: OptionallyConst<u64>>(seed: S, s: &str) -> u64 {
+        use core::hash::{BuildHasher, Hasher};
+
+        let mut hasher = SeededBuildHasher::new(seed).build_hasher();
+        hasher.write(s.as_bytes());
+        hasher.finish()
+    }
+
+    #[test]
+    fn test_const_seed() {
+        let const_hash = hash_str(ConstSeed::<42>, "optionally_const");
+        assert_eq!(const_hash, hash_str(ConstSeed::<42>, "optionally_const"));
+        assert_eq!(const_hash, hash_str(42u64, "optionally_const"));
+        assert_ne!(const_hash, hash_str(ConstSeed::<43>, "optionally_const"));
+        assert_ne!(const_hash, hash_str(43u64, "optionally_const"));
+    }
+
+    #[test]
+    fn test_const_seed_hash_map() {
+        let mut map: std::collections::HashMap<&str, u8, SeededBuildHasher<ConstSeed<7>>> =
+            std::collections::HashMap::with_hasher(SeededBuildHasher::new(ConstSeed::<7>));
+        map.insert("a", 1);
+        map.insert("b", 2);
+        assert_eq!(map.get("a"), Some(&1));
+        assert_eq!(map.get("b"), Some(&2));
+    }
+
+    struct GreeterBuilder {
+        name: &'static str,
+    }
+
+    impl<C: OptionallyConst<bool>> BuilderWith<GreeterBuilder, C> {
+        fn build(self) -> (String, bool) {
+            let is_const = self.const_value::<bool>().is_some();
+            let (builder, loud) = self.into_parts();
+            let greeting = if loud.into_value() {
+                format!("HELLO, {}!", builder.name)
+            } else {
+                format!("Hello, {}.", builder.name)
+            };
+            (greeting, is_const)
+        }
+    }
+
+    #[test]
+    fn test_with_const() {
+        let (greeting, is_const) = GreeterBuilder { name: "Ferris" }
+            .with_const(const_type_instance!(true))
+            .build();
+        assert_eq!(greeting, "HELLO, Ferris!");
+        assert!(is_const);
+
+        let (greeting, is_const) = GreeterBuilder { name: "Ferris" }
+            .with_const(false)
+            .build();
+        assert_eq!(greeting, "Hello, Ferris.");
+        assert!(!is_const);
+    }
+
+    fn print_flag<T: OptionallyConst<bool>>(flag: T) {
+        if let Some(flag) = T::MAYBE_CONST {
+            println!("flag is const: {flag}");
+        } else {
+            let flag: bool = flag.into_value();
+            println!("flag is not const: {flag}");
+        };
+    }
+
+    #[derive(Clone, Copy, PartialEq)]
+    enum MyEnum {
+        A,
+        B,
+        C,
+    }
+
+    impl std::fmt::Display for MyEnum {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                MyEnum::A => write!(f, "A"),
+                MyEnum::B => write!(f, "B"),
+                MyEnum::C => write!(f, "C"),
+            }
+        }
+    }
+
+    #[derive(Clone, Copy, PartialEq)]
+    struct MyEnumAConstType;
+    #[derive(Clone, Copy, PartialEq)]
+    struct MyEnumBConstType;
+    #[derive(Clone, Copy, PartialEq)]
+    struct MyEnumCConstType;
+
+    // Ideally, OptionallyConst<T> should be implemented for
+    // all types that implement Const<T>.
+    //
+    // However, impl of OptionallyConst<T> for all `T` conflicts with the
+    // impl of OptionallyConst<T> for all `U: Const<T>` in the absence of
+    // negative trait bounds.
+
+    impl_const!(MyEnumAConstType: MyEnum = MyEnum::A);
+
+    impl_optionally_const_via_const!(MyEnumAConstType: MyEnum);
+
+    impl_const!(MyEnumBConstType: MyEnum = MyEnum::B);
+
+    impl OptionallyConst<MyEnum> for MyEnumBConstType {
+        const MAYBE_CONST: Option<MyEnum> = Some(MyEnum::B);
+
+        fn into_value(self) -> MyEnum {
+            MyEnum::B
+        }
+
+        fn try_from_value(value: MyEnum) -> Result<Self, MyEnum> {
+            if matches!(value, MyEnum::B) {
+                Ok(MyEnumBConstType)
+            } else {
+                Err(value)
+            }
+        }
+    }
+
+    impl_const!(MyEnumCConstType: MyEnum = MyEnum::C);
+
+    impl OptionallyConst<MyEnum> for MyEnumCConstType {
+        const MAYBE_CONST: Option<MyEnum> = Some(MyEnum::C);
+
+        fn into_value(self) -> MyEnum {
+            MyEnum::C
+        }
+
+        fn try_from_value(value: MyEnum) -> Result<Self, MyEnum> {
+            if matches!(value, MyEnum::C) {
+                Ok(MyEnumCConstType)
+            } else {
+                Err(value)
+            }
+        }
+    }
+
+    fn print_my_enum<T: OptionallyConst<MyEnum>>(value: T) {
+        if let Some(value) = T::MAYBE_CONST {
+            println!("value is const: {value}");
+        } else {
+            let value: MyEnum = value.into_value();
+            println!("value is not const: {value}");
+        };
+    }
+
+    #[test]
+    fn test_output_bool() {
+        print_flag(true);
+        print_flag(false);
+        print_flag(const_type_instance!(true));
+        print_flag(const_type_instance!(false));
+    }
+
+    #[test]
+    fn test_custom_const_type_instance() {
+        let a: MyEnumAConstType = const_type_instance!(@custom MyEnumAConstType);
+        let a_value: MyEnum = a.into_value();
+        assert!(matches!(a_value, MyEnum::A));
+
+        print_my_enum(const_type_instance!(@custom MyEnumAConstType));
+        print_my_enum(const_type_instance!(@custom self::MyEnumBConstType));
+    }
+
+    #[cfg(feature = "generic_const_type")]
+    #[test]
+    fn test_generic_const_type() {
+        let a: ConstType<u8, 5> = ConstType::new();
+        let b: ConstType<char, 'x'> = ConstType::new();
+        let c: ConstTypeBool<true> = ConstType::<bool, true>::new();
+
+        let a_value: u8 = a.into_value();
+        let b_value: char = b.into_value();
+        let c_value: bool = c.into_value();
+
+        assert_eq!(a_value, 5);
+        assert_eq!(b_value, 'x');
+        assert!(c_value);
+        assert_eq!(<ConstType<u8, 5> as Const<u8>>::VALUE, 5);
+        assert_eq!(<ConstType<u8, 5> as OptionallyConst<u8>>::MAYBE_CONST, Some(5));
+        assert!(ConstType::<u8, 5>::try_from_value(6).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_const_type_bool() {
+        let json = serde_json::to_string(&const_type_instance!(true)).unwrap();
+        assert_eq!(json, "true");
+
+        let a: ConstTypeBool<true> = serde_json::from_str(&json).unwrap();
+        let a_value: bool = a.into_value();
+        assert!(a_value);
+
+        assert!(serde_json::from_str::<ConstTypeBool<false>>(&json).is_err());
+    }
+
+    #[cfg(feature = "defmt")]
+    #[test]
+    fn test_defmt_format() {
+        fn assert_format<T: defmt::Format>() {}
+
+        assert_format::<ConstTypeBool<true>>();
+        assert_format::<ConstTypeU8<1>>();
+        assert_format::<ConstTypeU16<1>>();
+        assert_format::<ConstTypeU32<1>>();
+        assert_format::<ConstTypeU64<1>>();
+        assert_format::<ConstTypeU128<1>>();
+        assert_format::<ConstTypeUsize<1>>();
+        assert_format::<ConstTypeI8<-1>>();
+        assert_format::<ConstTypeI16<-1>>();
+        assert_format::<ConstTypeI32<-1>>();
+        assert_format::<ConstTypeI64<-1>>();
+        assert_format::<ConstTypeI128<-1>>();
+        assert_format::<ConstTypeIsize<-1>>();
+        assert_format::<ConstTypeChar<'x'>>();
+        assert_format::<ConstSeed<1>>();
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn test_arbitrary() {
+        use arbitrary::{Arbitrary, Unstructured};
+
+        let data = [0xde, 0xad, 0xbe, 0xef];
+        let mut u = Unstructured::new(&data);
+        let flag = ConstTypeBool::<false>::arbitrary(&mut u).unwrap();
+        let count = ConstTypeI8::<-1>::arbitrary(&mut u).unwrap();
+        let flag_value: bool = flag.into_value();
+        let count_value: i8 = count.into_value();
+        assert!(!flag_value);
+        assert_eq!(count_value, -1);
+        assert_eq!(u.len(), data.len());
+        assert_eq!(ConstTypeChar::<'x'>::size_hint(0), (0, Some(0)));
+    }
+
+    // A carrier whose runtime value differs from its constant, which can only happen
+    // with a hand-written impl. It makes it observable which of the two is picked.
+    #[derive(Clone, Copy, PartialEq)]
+    struct Inconsistent(u32);
+
+    impl OptionallyConst<u32> for Inconsistent {
+        const MAYBE_CONST: Option<u32> = Some(1);
+
+        fn into_value(self) -> u32 {
+            self.0
+        }
+
+        fn try_from_value(value: u32) -> Result<Self, u32> {
+            Ok(Inconsistent(value))
+        }
+    }
+
+    fn const_or_value<T: OptionallyConst<u32>>(value: T) -> u32 {
+        const_or!(T, value.into_value())
+    }
+
+    #[test]
+    fn test_pick_and_const_or() {
+        const PICKED: u32 = pick(Some(1), 2);
+        const FALLBACK: u32 = pick(None, 2);
+        assert_eq!(PICKED, 1);
+        assert_eq!(FALLBACK, 2);
+
+        assert_eq!(const_or_value(Inconsistent(5)), 1);
+        assert_eq!(const_or_value(const_type_instance!(7: u32)), 7);
+        assert_eq!(const_or_value(core::hint::black_box(9u32)), 9);
+    }
+
+    fn scale<T: OptionallyConst<bool>>(flag: T, x: u64) -> u64 {
+        const_dispatch(
+            flag,
+            |flag| if flag { x * 3 } else { x + 7 },
+            |flag| if flag { x * 3 } else { x + 7 },
+        )
+    }
+
+    // Whether the runtime branch is eliminated can't be asserted from a test. It was checked
+    // by inspecting the assembly emitted for `scale::<ConstTypeBool<true>>` with
+    // `cargo rustc --release -- --emit=asm`: the const instantiation compiles to a single
+    // multiplication while the `bool` one keeps a conditional move. The `const_branch`
+    // benchmark measures the difference. This test checks that `black_box`, which hides
+    // the values from the optimizer, doesn't change the results of either path.
+    #[test]
+    fn test_const_branch_black_box() {
+        let flag: ConstTypeBool<true> = const_type_instance!(true);
+        assert_eq!(scale(core::hint::black_box(flag), core::hint::black_box(2)), 6);
+        assert_eq!(scale(core::hint::black_box(true), core::hint::black_box(2)), 6);
+        assert_eq!(scale(core::hint::black_box(false), core::hint::black_box(2)), 9);
+    }
+
+    const INHERENT_VALUES: (bool, u8, i128, char, u64) = (
+        ConstTypeBool::<true>::value(),
+        ConstTypeU8::<7>::value(),
+        ConstTypeI128::<-1>::value(),
+        ConstTypeChar::<'x'>::value(),
+        ConstSeed::<9>::value(),
+    );
+
+    #[test]
+    fn test_inherent_const_value() {
+        assert_eq!(INHERENT_VALUES, (true, 7, -1, 'x', 9));
+        const NON_ZERO: u32 = ConstTypeNonZeroU32::<5>::value().get();
+        assert_eq!(NON_ZERO, 5);
+        const TIMEOUT: core::time::Duration = ConstTypeDurationNanos::<1_000>::value();
+        assert_eq!(TIMEOUT, core::time::Duration::from_micros(1));
+
+        // The trait method returns the same values.
+        let flag: bool = const_type_instance!(true).into_value();
+        let small: u8 = const_type_instance!(7: u8).into_value();
+        let wide: i128 = const_type_instance!(-1: i128).into_value();
+        let c: char = const_type_instance!('x': char).into_value();
+        let seed: u64 = ConstSeed::<9>.into_value();
+        assert_eq!((flag, small, wide, c, seed), INHERENT_VALUES);
+        let ordering: core::cmp::Ordering = ConstTypeOrdering::<1>::new().into_value();
+        assert_eq!(ordering, ConstTypeOrdering::<1>::value());
+    }
+
+    #[test]
+    fn test_const_value_method() {
+        assert!(ConstTypeBool::<true>::value());
+        assert!(!ConstTypeBool::<false>::value());
+        assert_eq!(ConstTypeU8::<7>::value(), 7);
+        assert!(matches!(MyEnumAConstType::value(), MyEnum::A));
+    }
+
+    #[test]
+    fn test_ordering_const_type_instance() {
+        use core::cmp::Ordering;
+
+        let less: ConstTypeOrdering<-1> = const_type_instance!(Less);
+        let equal: ConstTypeOrdering<0> = const_type_instance!(Equal);
+        let greater: ConstTypeOrdering<1> = const_type_instance!(Greater);
+
+        let less_value: Ordering = less.into_value();
+        let equal_value: Ordering = equal.into_value();
+        let greater_value: Ordering = greater.into_value();
+
+        assert_eq!(less_value, Ordering::Less);
+        assert_eq!(equal_value, Ordering::Equal);
+        assert_eq!(greater_value, Ordering::Greater);
+        assert_eq!(<ConstTypeOrdering<-1> as Const<Ordering>>::VALUE, Ordering::Less);
+        assert_eq!(
+            <ConstTypeOrdering<1> as OptionallyConst<Ordering>>::MAYBE_CONST,
+            Some(Ordering::Greater)
+        );
+        assert!(ConstTypeOrdering::<0>::try_from_value(Ordering::Less).is_err());
+    }
+
+    fn describe_flag<T: OptionallyConst<bool>>(flag: T) -> String {
+        const_match!(flag => |v| format!("const {v}"), |x| format!("runtime {x}"))
+    }
+
+    #[test]
+    fn test_const_match() {
+        assert_eq!(describe_flag(const_type_instance!(true)), "const true");
+        assert_eq!(describe_flag(const_type_instance!(false)), "const false");
+        assert_eq!(describe_flag(true), "runtime true");
+        assert_eq!(describe_flag(NotConst(false)), "runtime false");
+    }
+
+    #[test]
+    fn test_ref_optionally_const() {
+        let a: ConstTypeBool<true> = const_type_instance!(true);
+        let b: ConstTypeU32<42> = const_type_instance!(42: u32);
+
+        assert_eq!(
+            <&ConstTypeBool<true> as OptionallyConst<bool>>::MAYBE_CONST,
+            Some(true)
+        );
+
+        let a_value: bool = (&a).into_value();
+        let b_value: u32 = (&b).into_value();
+        assert!(a_value);
+        assert_eq!(b_value, 42);
+
+        let a_ref: &ConstTypeBool<true> = &a;
+        print_flag(a_ref);
+        assert_eq!(describe_flag(a_ref), "const true");
+
+        assert!(<&ConstTypeU32<42>>::try_from_value(42).is_ok());
+        assert!(<&ConstTypeU32<42>>::try_from_value(43).is_err());
+    }
+
+    fn is_const_unit<T: OptionallyConst<()>>(unit: T) -> bool {
+        let () = unit.into_value();
+        T::MAYBE_CONST.is_some()
+    }
+
+    #[test]
+    fn test_const_unit() {
+        assert!(is_const_unit(ConstUnit));
+        assert!(!is_const_unit(()));
+        assert_eq!(<ConstUnit as OptionallyConst<()>>::MAYBE_CONST, Some(()));
+        assert!(ConstUnit::try_from_value(()).is_ok());
+    }
+
+    #[test]
+    fn test_hash_set() {
+        let mut set = std::collections::HashSet::new();
+        assert!(set.insert(const_type_instance!(true)));
+        assert!(!set.insert(const_type_instance!(true)));
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn test_hash_value() {
+        use core::hash::Hasher;
+
+        fn hash_of<T: OptionallyConst<bool>>(value: &T) -> u64 {
+            let mut hasher = SeededHasher::new(ConstSeed::<0>);
+            value.hash_value(&mut hasher);
+            hasher.finish()
+        }
+
+        let t: ConstTypeBool<true> = const_type_instance!(true);
+        assert_eq!(hash_of(&t), hash_of(&true));
+        assert_eq!(hash_of(&NotConst(true)), hash_of(&true));
+        assert_ne!(hash_of(&t), hash_of(&false));
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Direction {
+        Up,
+        Down,
+    }
+
+    const_carriers! {
+        Direction {
+            Up => UpConstType,
+            Down => DownConstType,
+        }
+    }
+
+    const_carriers! {
+        u8 {
+            1 => OneConstType,
+            { u8::MAX } => MaxConstType,
+        }
+    }
+
+    #[test]
+    fn test_const_carriers() {
+        assert_eq!(<UpConstType as Const<Direction>>::VALUE, Direction::Up);
+        assert_eq!(
+            <DownConstType as OptionallyConst<Direction>>::MAYBE_CONST,
+            Some(Direction::Down)
+        );
+        let up: Direction = UpConstType.into_value();
+        assert_eq!(up, Direction::Up);
+        assert!(UpConstType::try_from_value(Direction::Up).is_ok());
+        assert_eq!(
+            UpConstType::try_from_value(Direction::Down).err(),
+            Some(Direction::Down)
+        );
+
+        assert_eq!(<OneConstType as Const<u8>>::VALUE, 1);
+        assert_eq!(
+            <MaxConstType as OptionallyConst<u8>>::MAYBE_CONST,
+            Some(255)
+        );
+        let one: u8 = OneConstType.into_value();
+        assert_eq!(one, 1);
+        assert_eq!(OneConstType::try_from_value(2).err(), Some(2));
+        assert_eq!(describe_u8(OneConstType), "const 1");
+        assert_eq!(describe_u8(1), "runtime 1");
+    }
+
+    fn describe_u8<T: OptionallyConst<u8>>(value: T) -> String {
+        const_match!(value => |v| format!("const {v}"), |x| format!("runtime {x}"))
+    }
+
+    #[test]
+    fn test_non_zero_const_type() {
+        use core::num::{NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroUsize};
+
+        assert_eq!(<ConstTypeNonZeroU32<5> as Const<NonZeroU32>>::VALUE.get(), 5);
+        assert_eq!(<ConstTypeNonZeroU8<1> as Const<NonZeroU8>>::VALUE.get(), 1);
+        assert_eq!(<ConstTypeNonZeroU16<2> as Const<NonZeroU16>>::VALUE.get(), 2);
+        assert_eq!(<ConstTypeNonZeroU64<3> as Const<NonZeroU64>>::VALUE.get(), 3);
+        assert_eq!(<ConstTypeNonZeroUsize<4> as Const<NonZeroUsize>>::VALUE.get(), 4);
+
+        let id: ConstTypeNonZeroU32<5> = crate::hidden::ConstTypeNonZeroU32::<5>;
+        let id_value: NonZeroU32 = id.into_value();
+        assert_eq!(id_value, NonZeroU32::new(5).unwrap());
+        assert!(ConstTypeNonZeroU32::<5>::try_from_value(NonZeroU32::new(5).unwrap()).is_ok());
+        assert!(ConstTypeNonZeroU32::<5>::try_from_value(NonZeroU32::new(6).unwrap()).is_err());
+    }
+
+    #[test]
+    fn test_phantom_data_optionally_const() {
+        use core::marker::PhantomData;
+
+        assert_eq!(
+            <PhantomData<ConstTypeBool<true>> as OptionallyConst<bool>>::MAYBE_CONST,
+            Some(true)
+        );
+        let flag: bool = PhantomData::<ConstTypeBool<true>>.into_value();
+        assert!(flag);
+        print_flag(PhantomData::<ConstTypeBool<true>>);
+        assert_eq!(describe_flag(PhantomData::<ConstTypeBool<false>>), "const false");
+        assert!(<PhantomData<ConstTypeU8<3>>>::try_from_value(3).is_ok());
+        assert!(<PhantomData<ConstTypeU8<3>>>::try_from_value(4).is_err());
+    }
+
+    #[test]
+    fn test_maybe_const() {
+        struct Config {
+            flag: MaybeConst<ConstTypeBool<true>, bool>,
+        }
+
+        let const_config = Config {
+            flag: MaybeConst::Const(const_type_instance!(true)),
+        };
+        let dynamic_config = Config {
+            flag: MaybeConst::Dynamic(false),
+        };
+
+        assert_eq!(const_config.flag.maybe_const(), Some(true));
+        assert_eq!(dynamic_config.flag.maybe_const(), None);
+        assert_eq!(
+            <MaybeConst<ConstTypeBool<true>, bool> as OptionallyConst<bool>>::MAYBE_CONST,
+            None
+        );
+
+        let const_flag: bool = const_config.flag.into_value();
+        let dynamic_flag: bool = dynamic_config.flag.into_value();
+        assert!(const_flag);
+        assert!(!dynamic_flag);
+
+        assert!(matches!(
+            MaybeConst::<ConstTypeBool<true>, bool>::try_from_value(true),
+            Ok(MaybeConst::Const(_))
+        ));
+        assert!(matches!(
+            MaybeConst::<ConstTypeBool<true>, bool>::try_from_value(false),
+            Ok(MaybeConst::Dynamic(false))
+        ));
+    }
+
+    #[test]
+    fn test_const_array() {
+        assert_eq!(
+            <ConstArray<ConstTypeU8<0xFF>, 4> as Const<[u8; 4]>>::VALUE,
+            [0xFF; 4]
+        );
+
+        let splat: ConstArray<ConstTypeU8<0xFF>, 4> = ConstArray::new();
+        let splat_value: [u8; 4] = splat.into_value();
+        assert_eq!(splat_value, [0xFF; 4]);
+        assert!(ConstArray::<ConstTypeU8<0xFF>, 4>::try_from_value([0xFF; 4]).is_ok());
+        assert!(ConstArray::<ConstTypeU8<0xFF>, 4>::try_from_value([0xFF, 0, 0xFF, 0xFF]).is_err());
+    }
+
+    #[test]
+    fn test_try_from_another() {
+        let t: ConstTypeBool<true> = const_type_instance!(true);
+
+        assert!(matches!(
+            <ConstTypeBool<false> as OptionallyConst<bool>>::try_from_another(t),
+            Err(another) if another == t
+        ));
+        assert!(<ConstTypeBool<true> as OptionallyConst<bool>>::try_from_another(t).is_ok());
+        assert_eq!(<bool as OptionallyConst<bool>>::try_from_another(t).ok(), Some(true));
+        assert_eq!(<bool as OptionallyConst<bool>>::try_from_another(false), Ok(false));
+        assert_eq!(
+            <ConstTypeBool<true> as OptionallyConst<bool>>::try_from_another(false).err(),
+            Some(false)
+        );
+
+        // Distinct types that represent equal values are interchangeable.
+        let up: UpConstType = <UpConstType as OptionallyConst<Direction>>::try_from_another(
+            MaybeConst::<DownConstType, Direction>::Dynamic(Direction::Up),
+        )
+        .unwrap_or_else(|_| panic!("the values are equal"));
+        let up_value: Direction = up.into_value();
+        assert_eq!(up_value, Direction::Up);
+    }
+
+    #[test]
+    fn test_try_from_another_without_partial_eq() {
+        // `Id` is `Copy` but not `PartialEq`, so the blanket impl doesn't cover it.
+        #[derive(Clone, Copy)]
+        struct Id(u8);
+
+        #[derive(Clone, Copy, PartialEq)]
+        struct IdOne;
+
+        #[derive(Clone, Copy, PartialEq)]
+        struct FirstId;
+
+        impl_const! {
+            IdOne: Id = Id(1);
+            FirstId: Id = Id(1);
+        }
+
+        impl OptionallyConst<Id> for IdOne {
+            const MAYBE_CONST: Option<Id> = Some(Id(1));
+
+            fn into_value(self) -> Id {
+                Id(1)
+            }
+
+            fn try_from_value(value: Id) -> Result<Self, Id> {
+                if value.0 == 1 { Ok(IdOne) } else { Err(value) }
+            }
+        }
+
+        impl OptionallyConst<Id> for FirstId {
+            const MAYBE_CONST: Option<Id> = Some(Id(1));
+
+            fn into_value(self) -> Id {
+                Id(1)
+            }
+
+            fn try_from_value(value: Id) -> Result<Self, Id> {
+                if value.0 == 1 { Ok(FirstId) } else { Err(value) }
+            }
+        }
+
+        assert!(<IdOne as OptionallyConst<Id>>::try_from_another(FirstId).is_ok());
+        assert!(<FirstId as OptionallyConst<Id>>::try_from_another(IdOne).is_ok());
+    }
+
+    #[test]
+    fn test_fold() {
+        #[derive(Debug, PartialEq)]
+        enum Branch {
+            Const(u8),
+            Runtime(u8),
+        }
+
+        fn fold<T: OptionallyConst<u8>>(value: T) -> Branch {
+            value.fold(Branch::Const, Branch::Runtime)
+        }
+
+        assert_eq!(fold(ConstTypeU8::<7>::new()), Branch::Const(7));
+        assert_eq!(fold(7u8), Branch::Runtime(7));
+        assert_eq!(fold(NotConst(7u8)), Branch::Runtime(7));
+        assert_eq!(
+            fold(core::marker::PhantomData::<ConstTypeU8<9>>),
+            Branch::Const(9)
+        );
+
+        let flag = const_type_instance!(true);
+        let message: &str = OptionallyConst::<bool>::fold(flag, |_| "const", |_| "runtime");
+        assert_eq!(message, "const");
+    }
+
+    #[test]
+    fn test_as_const_unchecked() {
+        fn level<T: OptionallyConst<u8>>(value: T) -> u8 {
+            assert!(T::MAYBE_CONST.is_some());
+            // SAFETY: the assertion above guarantees that `T` represents a constant.
+            unsafe { value.as_const_unchecked() }
+        }
+
+        assert_eq!(level(ConstTypeU8::<3>::new()), 3);
+        assert_eq!(level(core::marker::PhantomData::<ConstTypeU8<9>>), 9);
+
+        let flag = const_type_instance!(true);
+        // SAFETY: `ConstTypeBool<true>` represents a constant.
+        let flag_value: bool = unsafe { OptionallyConst::<bool>::as_const_unchecked(flag) };
+        assert!(flag_value);
+
+        // Calling `level(3u8)` without the assertion would be undefined behavior
+        // because `u8` doesn't represent a constant, so it's not exercised here.
+    }
+
+    #[test]
+    fn test_const_not() {
+        assert_eq!(
+            <<ConstTypeBool<true> as ConstNot>::Output as OptionallyConst<bool>>::MAYBE_CONST,
+            <ConstTypeBool<false> as OptionallyConst<bool>>::MAYBE_CONST
+        );
+        assert_eq!(
+            <Not<ConstTypeBool<false>> as OptionallyConst<bool>>::MAYBE_CONST,
+            Some(true)
+        );
+        let _: ConstTypeBool<true> = <Not<Not<ConstTypeBool<true>>>>::new();
+    }
+
+    #[test]
+    fn test_const_and_or() {
+        fn maybe_const<C: OptionallyConst<bool>>() -> Option<bool> {
+            C::MAYBE_CONST
+        }
+
+        // `true && false == false`, computed at the type level.
+        assert_eq!(
+            maybe_const::<<ConstTypeBool<true> as ConstAnd<ConstTypeBool<false>>>::Output>(),
+            Some(false)
+        );
+        assert_eq!(maybe_const::<And<ConstTypeBool<true>, ConstTypeBool<true>>>(), Some(true));
+        assert_eq!(maybe_const::<And<ConstTypeBool<false>, ConstTypeBool<true>>>(), Some(false));
+        assert_eq!(maybe_const::<And<ConstTypeBool<false>, ConstTypeBool<false>>>(), Some(false));
+
+        assert_eq!(maybe_const::<Or<ConstTypeBool<true>, ConstTypeBool<false>>>(), Some(true));
+        assert_eq!(maybe_const::<Or<ConstTypeBool<false>, ConstTypeBool<true>>>(), Some(true));
+        assert_eq!(maybe_const::<Or<ConstTypeBool<true>, ConstTypeBool<true>>>(), Some(true));
+        assert_eq!(maybe_const::<Or<ConstTypeBool<false>, ConstTypeBool<false>>>(), Some(false));
+
+        // The operations compose with each other and with `ConstNot`.
+        type TrueOrFalse = Or<ConstTypeBool<true>, ConstTypeBool<false>>;
+        assert_eq!(
+            maybe_const::<Not<And<ConstTypeBool<true>, TrueOrFalse>>>(),
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn test_const_type_new() {
+        const FLAG: ConstTypeBool<true> = ConstTypeBool::<true>::new();
+        let flag: bool = FLAG.into_value();
+        assert!(flag);
+
+        let byte = const { ConstTypeU8::<7>::new() };
+        let byte_value: u8 = byte.into_value();
+        assert_eq!(byte_value, 7);
+
+        let id = const { ConstTypeNonZeroU32::<5>::new() };
+        assert!(id == ConstTypeNonZeroU32::<5>::default());
+    }
+
+    #[test]
+    fn test_const_type_default() {
+        let flag: bool = ConstTypeBool::<false>::default().into_value();
+        assert!(!flag);
+    }
+
+    #[test]
+    fn test_expect_const() {
+        let flag: bool = const_type_instance!(true).expect_const("the flag must be const");
+        assert!(flag);
+        let byte: u8 = ConstTypeU8::<3>::new().expect_const("the byte must be const");
+        assert_eq!(byte, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "the flag must be const")]
+    fn test_expect_const_runtime() {
+        let _: bool = true.expect_const("the flag must be const");
+    }
+
+    #[test]
+    fn test_const_value_of() {
+        const FLAG: bool = const_value_of::<bool, ConstTypeBool<true>>();
+        const { assert!(FLAG) };
+        assert!(!const_value_of::<_, ConstTypeBool<false>>());
+        assert_eq!(const_value_of::<u8, ConstTypeU8<9>>(), 9);
+    }
+
+    fn print_char<T: OptionallyConst<char>>(c: T) -> Option<char> {
+        if let Some(c) = T::MAYBE_CONST {
+            println!("char is const: {c:?}");
+        } else {
+            let c: char = c.into_value();
+            println!("char is not const: {c:?}");
+        };
+        T::MAYBE_CONST
+    }
+
+    #[test]
+    fn test_output_char() {
+        assert_eq!(print_char('a'), None);
+        assert_eq!(print_char(const_type_instance!('a': char)), Some('a'));
+        assert_eq!(print_char(NotConst('b')), None);
+        assert_eq!(print_char(const_type_instance!('c': char)), Some('c'));
+    }
+
+    #[test]
+    fn test_to_dynamic() {
+        let a: ConstTypeBool<true> = const_type_instance!(true);
+        let a_dynamic: bool = a.to_dynamic();
+        let a_value: bool = a.into_value();
+        assert_eq!(a_dynamic, a_value);
+        assert_eq!(false.to_dynamic(), false.into_value());
+    }
+
+    #[test]
+    fn test_partial_eq_bool() {
+        let t: ConstTypeBool<true> = const_type_instance!(true);
+        let f: ConstTypeBool<false> = const_type_instance!(false);
+
+        assert!(t == true);
+        assert!(t != false);
+        assert!(f == false);
+        assert!(true == t);
+        assert!(false != t);
+        assert!(false == f);
+    }
+
+    fn optimized_path<T: OptionallyConst<bool>>(flag: T) -> (bool, &'static str) {
+        const_if!(T, |v| (v, "optimized"), (flag.into_value(), "fallback"))
+    }
+
+    #[test]
+    fn test_const_if() {
+        assert_eq!(optimized_path(const_type_instance!(true)), (true, "optimized"));
+        assert_eq!(optimized_path(const_type_instance!(false)), (false, "optimized"));
+        assert_eq!(optimized_path(true), (true, "fallback"));
+        assert_eq!(optimized_path(NotConst(false)), (false, "fallback"));
+    }
+
+    #[test]
+    fn test_16_bit_const_types() {
+        assert_eq!(<ConstTypeU16<44_100> as Const<u16>>::VALUE, 44_100);
+        assert_eq!(<ConstTypeI16<-32_768> as Const<i16>>::VALUE, i16::MIN);
+
+        let port: ConstTypeU16<8080> = const_type_instance!(8080: u16);
+        let port_value: u16 = port.into_value();
+        assert_eq!(port_value, 8080);
+        let sample: ConstTypeI16<-1> = const_type_instance!(-1: i16);
+        let sample_value: i16 = sample.into_value();
+        assert_eq!(sample_value, -1);
+
+        assert_eq!(ConstTypeU16::<8080>::try_from_value(80).err(), Some(80));
+        assert_eq!(ConstTypeI16::<-1>::try_from_value(1).err(), Some(1));
+    }
+
+    #[test]
+    fn test_i8_i128_isize_const_types() {
+        assert_eq!(<ConstTypeI8<{ i8::MIN }> as Const<i8>>::VALUE, i8::MIN);
+        assert_eq!(<ConstTypeI8<0> as Const<i8>>::VALUE, 0);
+        assert_eq!(<ConstTypeI8<{ i8::MAX }> as Const<i8>>::VALUE, i8::MAX);
+        assert_eq!(<ConstTypeI128<{ i128::MIN }> as Const<i128>>::VALUE, i128::MIN);
+        assert_eq!(<ConstTypeI128<0> as Const<i128>>::VALUE, 0);
+        assert_eq!(<ConstTypeI128<{ i128::MAX }> as Const<i128>>::VALUE, i128::MAX);
+        assert_eq!(<ConstTypeIsize<{ isize::MIN }> as Const<isize>>::VALUE, isize::MIN);
+        assert_eq!(<ConstTypeIsize<0> as Const<isize>>::VALUE, 0);
+        assert_eq!(<ConstTypeIsize<{ isize::MAX }> as Const<isize>>::VALUE, isize::MAX);
+
+        let delta: ConstTypeI8<-128> = const_type_instance!(-128: i8);
+        let delta_value: i8 = delta.into_value();
+        assert_eq!(delta_value, i8::MIN);
+        let wide: ConstTypeI128<-1> = const_type_instance!(-1: i128);
+        let wide_value: i128 = wide.into_value();
+        assert_eq!(wide_value, -1);
+        let offset: ConstTypeIsize<-4> = const_type_instance!(-4: isize);
+        let offset_value: isize = offset.into_value();
+        assert_eq!(offset_value, -4);
+
+        assert_eq!(ConstTypeI8::<-1>::try_from_value(1).err(), Some(1));
+        assert_eq!(ConstTypeI128::<-1>::try_from_value(i128::MAX).err(), Some(i128::MAX));
+        assert_eq!(ConstTypeIsize::<0>::try_from_value(-1).err(), Some(-1));
+        assert!(ConstTypeIsize::<{ isize::MAX }>::try_from_value(isize::MAX).is_ok());
+    }
+
+    #[test]
+    fn test_duration_nanos_const_type() {
+        use core::time::Duration;
+
+        assert_eq!(
+            <ConstTypeDurationNanos<5_000_000> as Const<Duration>>::VALUE,
+            Duration::from_millis(5)
+        );
+        assert_eq!(
+            <ConstTypeDurationNanos<{ u64::MAX as u128 * 1_000_000_000 + 999_999_999 }> as Const<
+                Duration,
+            >>::VALUE,
+            Duration::MAX
+        );
+
+        let timeout: ConstTypeDurationNanos<5_000_000> = const_duration!(5, "ms");
+        let timeout_value: Duration = timeout.into_value();
+        assert_eq!(timeout_value, Duration::from_millis(5));
+        let _: ConstTypeDurationNanos<7> = const_duration!(7, "ns");
+        let _: ConstTypeDurationNanos<3_000> = const_duration!(3, "us");
+        let _: ConstTypeDurationNanos<2_000_000_000> = const_duration!(2, "s");
+
+        assert_eq!(
+            ConstTypeDurationNanos::<5_000_000>::try_from_value(Duration::from_millis(6)).err(),
+            Some(Duration::from_millis(6))
+        );
+        assert!(
+            ConstTypeDurationNanos::<1_500_000_000>::try_from_value(Duration::from_millis(1_500))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_usize_from_u32_const_type() {
+        fn len<T: OptionallyConst<usize>>(value: T) -> usize {
+            value.into_value()
+        }
+
+        assert_eq!(<ConstTypeUsizeFromU32<42> as Const<usize>>::VALUE, 42);
+        assert_eq!(len(ConstTypeUsizeFromU32::<42>::new()), 42);
+        let by_ref: &ConstTypeUsizeFromU32<7> = &ConstTypeUsizeFromU32::new();
+        assert_eq!(OptionallyConst::<usize>::into_value(by_ref), 7);
+        const MAX: usize = ConstTypeUsizeFromU32::<{ u32::MAX }>::value();
+        assert_eq!(MAX, u32::MAX as usize);
+        assert_eq!(
+            <ConstTypeUsizeFromU32<42> as OptionallyConst<usize>>::MAYBE_CONST,
+            Some(42)
+        );
+        assert!(ConstTypeUsizeFromU32::<42>::try_from_value(42).is_ok());
+        assert_eq!(ConstTypeUsizeFromU32::<42>::try_from_value(0).err(), Some(0));
+    }
+
+    #[test]
+    fn test_str_const_type() {
+        assert_eq!(
+            <ConstTypeStr<2, 0x6b6f> as Const<&'static str>>::VALUE,
+            "ok"
+        );
+        assert_eq!(<ConstTypeStr<0, 0> as Const<&'static str>>::VALUE, "");
+
+        let greeting: ConstTypeStr<6, { hidden::pack_str("héllo") }> = const_str!("héllo");
+        assert_eq!(ConstTypeStr::<6, { hidden::pack_str("héllo") }>::value(), "héllo");
+        let greeting_value: &'static str = greeting.into_value();
+        assert_eq!(greeting_value, "héllo");
+        let longest = const_str!("0123456789abcdef");
+        let longest_value: &'static str = longest.into_value();
+        assert_eq!(longest_value, "0123456789abcdef");
+
+        assert_eq!(
+            ConstTypeStr::<2, 0x6b6f>::try_from_value("no").err(),
+            Some("no")
+        );
+        assert!(ConstTypeStr::<2, 0x6b6f>::try_from_value("ok").is_ok());
+    }
+
+    #[test]
+    fn test_bytes_const_type() {
+        assert_eq!(
+            <ConstTypeBytes<2, 0x5a4d> as Const<[u8; 2]>>::VALUE,
+            *b"MZ"
+        );
+        assert_eq!(<ConstTypeBytes<0, 0> as Const<[u8; 0]>>::VALUE, *b"");
+
+        let elf = const_bytes!(b"\x7fELF");
+        let elf_value: [u8; 4] = elf.into_value();
+        assert_eq!(elf_value, *b"\x7fELF");
+        assert_eq!(
+            ConstTypeBytes::<4, { hidden::pack_bytes(b"\x7fELF") }>::value(),
+            *b"\x7fELF"
+        );
+        let longest = const_bytes!(b"0123456789abcde\xff");
+        let longest_value: [u8; 16] = longest.into_value();
+        assert_eq!(longest_value, *b"0123456789abcde\xff");
+
+        assert_eq!(
+            ConstTypeBytes::<2, 0x5a4d>::try_from_value(*b"ZM").err(),
+            Some(*b"ZM")
+        );
+        assert!(ConstTypeBytes::<2, 0x5a4d>::try_from_value(*b"MZ").is_ok());
+    }
+
+    #[test]
+    fn test_bool_ext() {
+        let t = const_type_instance!(true);
+        let f = const_type_instance!(false);
+
+        for (a, b) in [(false, false), (false, true), (true, false), (true, true)] {
+            assert_eq!(a.and(b), a && b);
+            assert_eq!(a.or(b), a || b);
+            assert_eq!(a.xor(b), a != b);
+        }
+
+        assert!(t.and(t));
+        assert!(!t.and(f));
+        assert!(!f.and(t));
+        assert!(t.or(f));
+        assert!(!f.or(f));
+        assert!(t.xor(f));
+        assert!(!t.xor(t));
+
+        for runtime in [false, true] {
+            assert_eq!(t.and(runtime), runtime);
+            assert!(!f.and(runtime));
+            assert!(!runtime.and(f));
+            assert!(t.or(runtime));
+            assert!(runtime.or(t));
+            assert_eq!(f.or(runtime), runtime);
+            assert_eq!(t.xor(runtime), !runtime);
+            assert_eq!(runtime.xor(f), runtime);
+        }
+    }
+
+    fn low_bits<T: OptionallyConst<u128>>(value: T) -> (u64, bool) {
+        let value: u128 = value.into_value();
+        (value as u64, T::MAYBE_CONST.is_some())
+    }
+
+    #[test]
+    fn test_64_and_128_bit_const_types() {
+        assert_eq!(<ConstTypeU64<{ u64::MAX }> as Const<u64>>::VALUE, u64::MAX);
+        assert_eq!(<ConstTypeU128<{ u128::MAX }> as Const<u128>>::VALUE, u128::MAX);
+
+        let offset: ConstTypeU64<0xcbf2_9ce4_8422_2325> =
+            const_type_instance!(0xcbf2_9ce4_8422_2325: u64);
+        let offset_value: u64 = offset.into_value();
+        assert_eq!(offset_value, 0xcbf2_9ce4_8422_2325);
+        assert_eq!(ConstTypeU64::<1>::try_from_value(2).err(), Some(2));
+        assert_eq!(ConstTypeU128::<1>::try_from_value(2).err(), Some(2));
+
+        let prime: ConstTypeU128<0x0000_0000_0100_0000_0000_0000_0000_013b> =
+            const_type_instance!(0x0000_0000_0100_0000_0000_0000_0000_013b: u128);
+        assert_eq!(low_bits(prime), (0x013b, true));
+        assert_eq!(low_bits(u128::MAX), (u64::MAX, false));
+        assert_eq!(low_bits(ConstTypeU128::<{ u128::MAX }>::new()), (u64::MAX, true));
+    }
+
+    fn assert_optionally_const<T, C: OptionallyConst<T>>() {}
+
+    #[test]
+    fn test_numeric_const_types_bound() {
+        assert_optionally_const::<u8, ConstTypeU8<1>>();
+        assert_optionally_const::<u64, ConstTypeU64<1>>();
+        assert_optionally_const::<i16, ConstTypeI16<-1>>();
+        assert_optionally_const::<i8, ConstTypeI8<-1>>();
+        assert_optionally_const::<i128, ConstTypeI128<-1>>();
+        assert_optionally_const::<isize, ConstTypeIsize<-1>>();
+    }
+
+    #[test]
+    fn test_try_from_value_err() {
+        let err = ConstTypeBool::<true>::try_from_value_err(false).err().unwrap();
+        assert_eq!(
+            err,
+            ConstMismatch {
+                value: false,
+                expected: Some(true)
+            }
+        );
+        assert_eq!(err.to_string(), "expected const true, got false");
+        assert!(ConstTypeBool::<true>::try_from_value_err(true).is_ok());
+        assert_eq!(<bool as OptionallyConst<bool>>::try_from_value_err(false), Ok(false));
+    }
+
+    fn parse_const_flag(s: &str) -> Result<ConstTypeBool<true>, Box<dyn std::error::Error>> {
+        let flag: bool = s.parse()?;
+        Ok(ConstTypeBool::<true>::try_from_value_err(flag)?)
+    }
+
+    #[test]
+    fn test_const_mismatch_error() {
+        assert!(parse_const_flag("true").is_ok());
+        let err = parse_const_flag("false").err().unwrap();
+        assert_eq!(err.to_string(), "expected const true, got false");
+        assert!(parse_const_flag("maybe").is_err());
+    }
+
+    #[test]
+    fn test_value_eq() {
+        let t: ConstTypeBool<true> = const_type_instance!(true);
+        let f: ConstTypeBool<false> = const_type_instance!(false);
+
+        assert!(t.value_eq(true));
+        assert!(!t.value_eq(false));
+        assert!(!OptionallyConst::<bool>::value_eq(t, f));
+        assert!(OptionallyConst::<bool>::value_eq(t, t));
+        assert!(false.value_eq(f));
+    }
+
+    #[test]
+    fn test_pair() {
+        let t: ConstTypeBool<true> = const_type_instance!(true);
+        let seven: ConstTypeU8<7> = const_type_instance!(7: u8);
+
+        assert_eq!(<Pair<ConstTypeBool<true>, u8> as OptionallyConst<(bool, u8)>>::MAYBE_CONST, None);
+        assert_eq!(
+            <Pair<ConstTypeBool<true>, ConstTypeU8<7>> as OptionallyConst<(bool, u8)>>::MAYBE_CONST,
+            Some((true, 7))
+        );
+
+        let mixed: (bool, u8) = Pair(t, 3u8).into_value();
+        assert_eq!(mixed, (true, 3));
+        let both: (bool, u8) = Pair(t, seven).into_value();
+        assert_eq!(both, (true, 7));
+
+        assert!(Pair::<ConstTypeBool<true>, u8>::try_from_value((true, 3)).is_ok());
+        assert_eq!(
+            Pair::<ConstTypeBool<true>, u8>::try_from_value((false, 3)).err(),
+            Some((false, 3))
+        );
+    }
+
+    #[test]
+    fn test_const_value_and_into_option() {
+        let t: ConstTypeBool<true> = const_type_instance!(true);
+        assert_eq!(OptionallyConst::<bool>::const_value(t), Some(true));
+        assert_eq!(OptionallyConst::<bool>::into_option(t), Some(true));
+
+        assert_eq!(OptionallyConst::<bool>::const_value(false), None);
+        assert_eq!(OptionallyConst::<bool>::into_option(false), Some(false));
+
+        let seven: ConstTypeU8<7> = const_type_instance!(7: u8);
+        assert_eq!(OptionallyConst::<u8>::const_value(seven), Some(7));
+        assert_eq!(OptionallyConst::<u8>::const_value(7u8), None);
+        assert_eq!(OptionallyConst::<u8>::into_option(7u8), Some(7));
+    }
+
+    #[test]
+    fn test_try_from_bool() -> Result<(), bool> {
+        let t: ConstTypeBool<true> = true.try_into()?;
+        assert!(t == true);
+        assert!(ConstTypeBool::<false>::try_from(false).is_ok());
+        assert_eq!(ConstTypeBool::<true>::try_from(false).err(), Some(false));
+        assert_eq!(ConstTypeBool::<false>::try_from(true).err(), Some(true));
+        Ok(())
+    }
+
+    #[test]
+    fn test_into_value_type() {
+        fn into_u32(value: impl Into<u32>) -> u32 {
+            value.into()
+        }
+
+        let t: bool = const_type_instance!(true).into();
+        assert!(t);
+        let seven: u8 = const_type_instance!(7: u8).into();
+        assert_eq!(seven, 7);
+        let x: char = const_type_instance!('x': char).into();
+        assert_eq!(x, 'x');
+        assert_eq!(into_u32(ConstTypeU32::<42>::new()), 42);
+        assert_eq!(into_u32(42u32), 42);
+    }
+
+    #[test]
+    fn test_const_option() {
+        assert_eq!(
+            <ConstSome<ConstTypeU8<42>> as OptionallyConst<Option<u8>>>::MAYBE_CONST,
+            Some(Some(42))
+        );
+        assert_eq!(<ConstSome<ConstTypeBool<true>> as Const<Option<bool>>>::VALUE, Some(true));
+        assert_eq!(<ConstNone<u8> as OptionallyConst<Option<u8>>>::MAYBE_CONST, Some(None));
+
+        let some: Option<u8> = ConstSome::<ConstTypeU8<42>>::new().into_value();
+        assert_eq!(some, Some(42));
+        assert!(ConstSome::<ConstTypeU8<42>>::try_from_value(Some(42)).is_ok());
+        assert_eq!(ConstSome::<ConstTypeU8<42>>::try_from_value(None).err(), Some(None));
+        assert!(ConstNone::<u8>::try_from_value(None).is_ok());
+        assert_eq!(ConstNone::<u8>::try_from_value(Some(1)).err(), Some(Some(1)));
+    }
+
+    #[test]
+    fn test_const_dispatch() {
+        fn dispatch<T: OptionallyConst<u8>>(carrier: T) -> (&'static str, u8) {
+            const_dispatch(carrier, |v| ("const", v), |v| ("runtime", v))
+        }
+
+        assert_eq!(dispatch(const_type_instance!(7: u8)), ("const", 7));
+        assert_eq!(dispatch(7u8), ("runtime", 7));
+        assert!(const_dispatch(ConstTypeBool::<false>::new(), |v: bool| !v, |_| false));
+    }
+
+    #[test]
+    fn test_try_map() {
+        fn check(flag: bool) -> Result<&'static str, &'static str> {
+            if flag { Ok("enabled") } else { Err("disabled") }
+        }
+
+        let t: ConstTypeBool<true> = const_type_instance!(true);
+        let f: ConstTypeBool<false> = const_type_instance!(false);
+        assert_eq!(OptionallyConst::<bool>::try_map(t, check), Ok("enabled"));
+        assert_eq!(OptionallyConst::<bool>::try_map(f, check), Err("disabled"));
+        assert_eq!(OptionallyConst::<bool>::try_map(true, check), Ok("enabled"));
+        assert_eq!(OptionallyConst::<bool>::try_map(false, check), Err("disabled"));
+    }
+
+    #[test]
+    fn test_const_type_wrapping() {
+        use core::num::Wrapping;
+
+        // The runtime values are covered by the blanket impl.
+        assert_eq!(<Wrapping<u8> as OptionallyConst<Wrapping<u8>>>::MAYBE_CONST, None);
+
+        assert_eq!(
+            <ConstTypeWrapping<ConstTypeU8<255>> as OptionallyConst<Wrapping<u8>>>::MAYBE_CONST,
+            Some(Wrapping(255))
+        );
+        let max: Wrapping<u8> = ConstTypeWrapping::<ConstTypeU8<255>>::new().into_value();
+        assert_eq!(max + Wrapping(1), Wrapping(0));
+        assert!(ConstTypeWrapping::<ConstTypeU8<255>>::try_from_value(Wrapping(255)).is_ok());
+        assert_eq!(
+            ConstTypeWrapping::<ConstTypeU8<255>>::try_from_value(Wrapping(0)).err(),
+            Some(Wrapping(0))
+        );
+    }
+
+    #[test]
+    fn test_const_type_reverse() {
+        use core::cmp::Reverse;
+
+        // The runtime values are covered by the blanket impl.
+        assert_eq!(<Reverse<u8> as OptionallyConst<Reverse<u8>>>::MAYBE_CONST, None);
+
+        assert_eq!(
+            <ConstTypeReverse<ConstTypeU8<7>> as Const<Reverse<u8>>>::VALUE,
+            Reverse(<ConstTypeU8<7> as Const<u8>>::VALUE)
+        );
+        let seven: Reverse<u8> = ConstTypeReverse::<ConstTypeU8<7>>::new().into_value();
+        assert!(seven < Reverse(3));
+        assert!(ConstTypeReverse::<ConstTypeU8<7>>::try_from_value(Reverse(7)).is_ok());
+        assert_eq!(
+            ConstTypeReverse::<ConstTypeU8<7>>::try_from_value(Reverse(3)).err(),
+            Some(Reverse(3))
+        );
+    }
+
+    #[test]
+    fn test_impl_optionally_const_via_const() {
+        assert!(matches!(
+            <MyEnumAConstType as OptionallyConst<MyEnum>>::MAYBE_CONST,
+            Some(MyEnum::A)
+        ));
+        let a: MyEnum = MyEnumAConstType.into_value();
+        assert!(matches!(a, MyEnum::A));
+        assert!(MyEnumAConstType::try_from_value(MyEnum::A).is_ok());
+        assert!(matches!(
+            MyEnumAConstType::try_from_value(MyEnum::B).err(),
+            Some(MyEnum::B)
+        ));
+
+        fn represents_a<C: IsConst<MyEnum>>(carrier: C) -> bool {
+            matches!(
+                (C::MAYBE_CONST, carrier.into_value()),
+                (Some(MyEnum::A), MyEnum::A)
+            )
+        }
+
+        assert!(represents_a(MyEnumAConstType));
+    }
+
+    #[test]
+    fn test_const_val() {
+        fn value_of<C: ConstVal>() -> C::Value {
+            C::VALUE
+        }
+
+        assert!(value_of::<ConstTypeBool<true>>());
+        assert_eq!(value_of::<ConstTypeU16<300>>(), 300);
+        assert_eq!(value_of::<ConstTypeChar<'x'>>(), 'x');
+        assert_eq!(value_of::<ConstArray<ConstTypeU8<1>, 3>>(), [1, 1, 1]);
+        assert_eq!(value_of::<ConstSome<ConstTypeI32<-1>>>(), Some(-1));
+        assert_eq!(value_of::<ConstNone<u8>>(), None);
+        assert_eq!(
+            value_of::<ConstTypeWrapping<ConstTypeU8<255>>>(),
+            core::num::Wrapping(255)
+        );
+    }
+
+    #[test]
+    fn test_value_by_ref() {
+        struct Config<F: OptionallyConst<bool>> {
+            flag: F,
+        }
+
+        let config = Config {
+            flag: ConstTypeBool::<true>::new(),
+        };
+        let config_ref: &Config<ConstTypeBool<true>> = &config;
+        assert!(OptionallyConst::<bool>::value_by_ref(&config_ref.flag));
+
+        let config = Config { flag: false };
+        assert!(!config.flag.value_by_ref());
+    }
+
+    #[test]
+    fn test_const_type_instance_bool_expr() {
+        const VERBOSE: bool = false;
+
+        let eq: ConstTypeBool<true> = const_type_instance!(1 == 1);
+        let test: ConstTypeBool<true> = const_type_instance!({ cfg!(test) });
+        let verbose: ConstTypeBool<false> = const_type_instance!(VERBOSE);
+        let both: ConstTypeBool<false> = const_type_instance!(VERBOSE && u8::MAX == 255);
+        assert!(eq == true);
+        assert!(test == true);
+        assert!(verbose == false);
+        assert!(both == false);
     }
 
     #[test]
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+use crate::ConstTypeBool;
+
+impl<const VAL: bool> Serialize for ConstTypeBool<VAL> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_bool(VAL)
+    }
+}
+
+impl<'de, const VAL: bool> Deserialize<'de> for ConstTypeBool<VAL> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = bool::deserialize(deserializer)?;
+        if value == VAL {
+            Ok(crate::hidden::const_type_bool::<VAL>())
+        } else {
+            Err(de::Error::invalid_value(
+                de::Unexpected::Bool(value),
+                &if VAL { "`true`" } else { "`false`" },
+            ))
+        }
+    }
+}
@@ -1,31 +1,115 @@
 use proc_macro::TokenStream;
 
 use derive_syn_parse::Parse;
-use quote::quote;
+use quote::{format_ident, quote};
 use syn::{DeriveInput, parse_macro_input};
 
-fn find_const_type_attr(attrs: &[syn::Attribute]) -> &syn::Attribute {
-    attrs
-        .iter()
-        .find(|attr| {
-            attr.path()
-                .get_ident()
-                .is_some_and(|ident| ident == "const_type")
-        })
-        .unwrap_or_else(|| {
-            panic!("Expected #[const_type(ConstTypeName)] attribute");
-        })
+fn is_const_type_attr(attr: &syn::Attribute) -> bool {
+    attr.path()
+        .get_ident()
+        .is_some_and(|ident| ident == "const_type")
 }
 
 #[derive(Parse)]
 struct ConstTypeSyntax {
     #[call(syn::Attribute::parse_outer)]
     attrs: Vec<syn::Attribute>,
+    vis: syn::Visibility,
     name: syn::Ident,
+    #[call(parse_where_predicates)]
+    where_predicates: Vec<syn::WherePredicate>,
+    #[call(ConstTypeOptions::parse_trailing)]
+    options: ConstTypeOptions,
 }
 
-fn const_type_syntax(attrs: &[syn::Attribute]) -> ConstTypeSyntax {
-    let const_type_name_attr: &syn::Attribute = find_const_type_attr(attrs);
+/// Parses the optional `where` clause following the name of the const type
+/// in the `#[const_type(...)]` attribute.
+///
+/// The predicates and the options are both comma-separated, so a comma followed by
+/// an identifier that looks like an option ends the `where` clause and is left
+/// for [`ConstTypeOptions::parse_trailing`].
+fn parse_where_predicates(input: syn::parse::ParseStream) -> syn::Result<Vec<syn::WherePredicate>> {
+    let mut predicates = Vec::new();
+    if !input.peek(syn::Token![where]) {
+        return Ok(predicates);
+    }
+    input.parse::<syn::Token![where]>()?;
+    loop {
+        predicates.push(input.parse()?);
+        if input.is_empty() {
+            break;
+        }
+        let fork = input.fork();
+        fork.parse::<syn::Token![,]>()?;
+        let is_option: bool = fork.is_empty()
+            || (fork.peek(syn::Ident)
+                && (fork.peek2(syn::Token![,])
+                    || fork.peek2(syn::token::Paren)
+                    || fork.peek2(syn::Token![=])
+                    || {
+                        let option_fork = fork.fork();
+                        option_fork.parse::<syn::Ident>()?;
+                        option_fork.is_empty()
+                    }));
+        if is_option {
+            break;
+        }
+        input.parse::<syn::Token![,]>()?;
+    }
+    Ok(predicates)
+}
+
+/// The comma-separated options following the name of the const type
+/// in the `#[const_type(...)]` attribute.
+#[derive(Default)]
+struct ConstTypeOptions {
+    align: Option<syn::LitInt>,
+    doc: Option<syn::LitStr>,
+    from_str: bool,
+    display: bool,
+    cross_eq: bool,
+    no_try_into: bool,
+    stable_discriminants: bool,
+}
+
+impl ConstTypeOptions {
+    fn parse_trailing(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut options = ConstTypeOptions::default();
+        while !input.is_empty() {
+            input.parse::<syn::Token![,]>()?;
+            if input.is_empty() {
+                break;
+            }
+            let option: syn::Ident = input.parse()?;
+            if option == "align" {
+                let content;
+                syn::parenthesized!(content in input);
+                options.align = Some(content.parse()?);
+            } else if option == "doc" {
+                input.parse::<syn::Token![=]>()?;
+                options.doc = Some(input.parse()?);
+            } else if option == "from_str" {
+                options.from_str = true;
+            } else if option == "display" {
+                options.display = true;
+            } else if option == "cross_eq" {
+                options.cross_eq = true;
+            } else if option == "no_try_into" {
+                options.no_try_into = true;
+            } else if option == "stable_discriminants" {
+                options.stable_discriminants = true;
+            } else {
+                return Err(syn::Error::new(
+                    option.span(),
+                    format!("unknown #[const_type(...)] option `{option}`"),
+                ));
+            }
+        }
+        Ok(options)
+    }
+}
+
+fn const_type_syntax(const_type_name_attr: &syn::Attribute) -> ConstTypeSyntax {
     let meta: &syn::Meta = &const_type_name_attr.meta;
     let syn::Meta::List(list) = meta else {
         panic!("Expected #[const_type(ConstTypeName)] attribute to be a list");
@@ -36,11 +120,57 @@ fn const_type_syntax(attrs: &[syn::Attribute]) -> ConstTypeSyntax {
         tokens,
     } = list;
 
-    syn::parse2(tokens.clone()).unwrap_or_else(|_| {
-        panic!("Expected #[const_type(ConstTypeName)] attribute to contain a single identifier");
+    syn::parse2(tokens.clone()).unwrap_or_else(|err| {
+        panic!("Failed to parse #[const_type(ConstTypeName, options...)] attribute: {err}");
     })
 }
 
+fn const_type_syntaxes(attrs: &[syn::Attribute]) -> Vec<ConstTypeSyntax> {
+    let syntaxes: Vec<ConstTypeSyntax> = attrs
+        .iter()
+        .filter(|attr| is_const_type_attr(attr))
+        .map(const_type_syntax)
+        .collect();
+    assert!(
+        !syntaxes.is_empty(),
+        "Expected #[const_type(ConstTypeName)] attribute"
+    );
+    syntaxes
+}
+
+// Rejects the derives of the traits that are implemented for the const type by the derive macro
+// because they would conflict with the generated impls, e.g. `Default` or, with the `cross_eq`
+// option, `PartialEq`.
+fn derive_errors(attrs: &[syn::Attribute], trait_name: &str, reason: &str) -> Option<syn::Error> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("derive"))
+        .flat_map(|attr| {
+            attr.parse_args_with(
+                syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated,
+            )
+            .unwrap_or_else(|err| panic!("Failed to parse #[derive(...)] attribute: {err}"))
+        })
+        .filter(|path| {
+            path.segments
+                .last()
+                .is_some_and(|segment| segment.ident == trait_name)
+        })
+        .map(|path| {
+            syn::Error::new_spanned(
+                path,
+                format!(
+                    "{reason} implements `{trait_name}` for the const type, so it must not \
+                    be derived in #[const_type(...)]"
+                ),
+            )
+        })
+        .reduce(|mut errors, error| {
+            errors.combine(error);
+            errors
+        })
+}
+
 fn assert_fieldless_enum(data_enum: &syn::DataEnum) {
     for variant in &data_enum.variants {
         assert!(
@@ -56,6 +186,70 @@ fn assert_fieldless_enum(data_enum: &syn::DataEnum) {
 /// of the [const type] that represent the enum variants.
 ///
 /// The fieldless enum also must derive the [`Clone`] and [`Copy`] traits.
+/// Generic enums are not supported.
+///
+/// The `#[cfg(...)]` attributes on the variants are respected, so the generated code
+/// doesn't refer to the variants that are configured out.
+///
+/// `#[non_exhaustive]` enums are supported. The generated code lives in the defining crate and
+/// covers only the variants known at the time of derivation, so the variants added later
+/// get their const types as soon as the code is re-derived.
+///
+/// The const type is parameterized by the [discriminant]s of the variants converted to a `usize`,
+/// not by their positions. Explicit discriminant expressions, e.g. `A = 1 << 2`, are respected.
+/// The discriminant is also available from an instance via the `discriminant()` const method.
+///
+/// The attributes preceding the name of the const type in `#[const_type(...)]` are
+/// placed on the generated const type. This way, you can opt into additional derives,
+/// e.g. `#[derive(Clone, Copy, PartialEq, Eq, Hash)]` to use the const type
+/// as a key in a `HashMap` or a `HashSet`.
+///
+/// The generated const type has the same visibility as the enum. It can be overridden by
+/// a visibility specifier before the name of the const type, e.g. `#[const_type(pub ConstTypeName)]`.
+/// The [`ConstVal`] trait is implemented for the const type only if it's at most as visible
+/// as the enum because the associated type [`ConstVal::Value`] can't be less visible than the
+/// const type. `pub(super)` and `pub(in path)` are considered comparable only to themselves.
+///
+/// The name of the const type can be immediately followed by a `where` clause, e.g.
+/// `#[const_type(ConstTypeName where usize: SomeTrait)]`. The predicates are attached to the
+/// generated const type and to all of its impls.
+///
+/// The name of the const type can be followed by comma-separated options:
+///
+/// * `align(N)` sets the alignment of the const type to `N` bytes with `#[repr(align(N))]`.
+/// * `doc = "..."` prepends custom documentation to the generated documentation of the const type.
+/// * `from_str` implements [`FromStr`](core::str::FromStr) for the const type. The string
+///   is parsed successfully only if it's the name of the represented variant.
+/// * `display` implements [`Display`](core::fmt::Display) for the const type by forwarding
+///   to the [`Display`](core::fmt::Display) impl of the enum, which is required.
+/// * `cross_eq` implements [`PartialEq`] between any two parameterizations of the const type,
+///   which compares the discriminants. The impl covers the comparison of the const type with
+///   itself, so [`PartialEq`] must not be derived in `#[const_type(...)]` with this option.
+/// * `stable_discriminants` requires an explicit discriminant on every variant of the enum,
+///   e.g. `A = 0`. Otherwise, adding, removing, or reordering the variants shifts the positional
+///   discriminants and silently changes which parameterization of the const type represents
+///   which variant. Since the discriminants belong to the enum, the option applies
+///   if it's specified in any `#[const_type(...)]` attribute.
+/// * `no_try_into` skips the generation of the `try_into_const_type_instance` method on the enum.
+///   Since the method returns an instance of the first const type, the option has an effect only
+///   in the first `#[const_type(...)]` attribute.
+///
+/// For each const type, e.g. `ConstTypeName`, a runtime enum `ConstTypeNameAny` is generated.
+/// It has a variant wrapping the parameterization of the const type for each variant of
+/// the fieldless enum, and implements [`TryFrom<usize>`] to convert a runtime discriminant
+/// into the matching variant.
+///
+/// With the `proptest` feature of the `optionally_const` crate, the enum also gets
+/// a `strategy()` function that returns a [proptest] strategy generating its variants.
+/// The const types are single-valued, so the strategy targets the enum. It's available only
+/// if the enum implements [`Debug`](core::fmt::Debug).
+///
+/// The const type always implements [`Default`], so a [`Default`] derive in `#[const_type(...)]`
+/// is rejected.
+///
+/// The `#[const_type(...)]` attribute can be specified multiple times to derive several
+/// const types for one enum. The `try_into_const_type_instance` method on the enum returns
+/// an instance of the first one.
 ///
 /// # Example
 ///
@@ -100,8 +294,12 @@ fn assert_fieldless_enum(data_enum: &syn::DataEnum) {
 ///
 /// [fieldless enum]: https://doc.rust-lang.org/reference/items/enumerations.html#r-items.enum.fieldless
 /// [const type]: https://github.com/JohnScience/optionally_const/tree/main/optionally_const#const-type
+/// [discriminant]: https://doc.rust-lang.org/reference/items/enumerations.html#discriminants
+/// [proptest]: https://docs.rs/proptest
 /// [`Const`]: https://docs.rs/optionally_const/latest/optionally_const/trait.Const.html
 /// [`OptionallyConst`]: https://docs.rs/optionally_const/latest/optionally_const/trait.OptionallyConst.html
+/// [`ConstVal`]: https://docs.rs/optionally_const/latest/optionally_const/trait.ConstVal.html
+/// [`ConstVal::Value`]: https://docs.rs/optionally_const/latest/optionally_const/trait.ConstVal.html#associatedtype.Value
 #[allow(clippy::missing_panics_doc, clippy::too_many_lines)]
 #[proc_macro_derive(FieldlessEnumConstType, attributes(const_type))]
 pub fn derive_fieldless_enum_const_type(input: TokenStream) -> TokenStream {
@@ -112,16 +310,29 @@ pub fn derive_fieldless_enum_const_type(input: TokenStream) -> TokenStream {
         // Should we care about the visibility?
         vis,
         ident,
-        generics: _no_generics,
+        generics,
         data,
     } = input;
 
+    if !generics.params.is_empty() {
+        return syn::Error::new_spanned(
+            &generics,
+            "#[derive(FieldlessEnumConstType)] doesn't support generic enums yet",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    // Every `#[const_type(...)]` attribute declares a const type. The first one is the primary
+    // const type, which is returned by the `try_into_const_type_instance` method on the enum.
+    let const_type_syntaxes: Vec<ConstTypeSyntax> = const_type_syntaxes(&attrs);
+
     // The identifier of the generic type whose parameterizations will be used to
     // represent the const values of the enum variants.
-    let ConstTypeSyntax {
-        attrs: const_type_attrs,
-        name: const_type_ident,
-    } = const_type_syntax(&attrs);
+    let const_type_ident: syn::Ident = const_type_syntaxes[0].name.clone();
+    let const_type_where_predicates: Vec<syn::WherePredicate> =
+        const_type_syntaxes[0].where_predicates.clone();
+    let no_try_into: bool = const_type_syntaxes[0].options.no_try_into;
 
     let syn::Data::Enum(data_enum) = data else {
         panic!("#[derive(FieldlessEnumConstType)] can only be used on enums.");
@@ -129,9 +340,340 @@ pub fn derive_fieldless_enum_const_type(input: TokenStream) -> TokenStream {
 
     assert_fieldless_enum(&data_enum);
 
-    let variants = data_enum.variants.iter().map(|variant| &variant.ident);
+    // Positional discriminants shift when the variants are added, removed, or reordered,
+    // which silently changes the variants that the parameterizations of the const type represent.
+    let stable_discriminants: bool = const_type_syntaxes
+        .iter()
+        .any(|const_type_syntax| const_type_syntax.options.stable_discriminants);
+    if stable_discriminants {
+        let errors: Option<syn::Error> = data_enum
+            .variants
+            .iter()
+            .filter(|variant| variant.discriminant.is_none())
+            .map(|variant| {
+                syn::Error::new_spanned(
+                    variant,
+                    format!(
+                        "variant `{}` must have an explicit discriminant \
+                        because of the `stable_discriminants` option",
+                        variant.ident
+                    ),
+                )
+            })
+            .reduce(|mut errors, error| {
+                errors.combine(error);
+                errors
+            });
+        if let Some(errors) = errors {
+            return errors.to_compile_error().into();
+        }
+    }
+
+    let conflicting_derive_errors: Option<syn::Error> = const_type_syntaxes
+        .iter()
+        .flat_map(|const_type_syntax| {
+            let attrs: &[syn::Attribute] = &const_type_syntax.attrs;
+            let partial_eq_errors: Option<syn::Error> = if const_type_syntax.options.cross_eq {
+                derive_errors(attrs, "PartialEq", "the `cross_eq` option")
+            } else {
+                None
+            };
+            [derive_errors(attrs, "Default", "the derive macro"), partial_eq_errors]
+        })
+        .flatten()
+        .reduce(|mut errors, error| {
+            errors.combine(error);
+            errors
+        });
+    if let Some(errors) = conflicting_derive_errors {
+        return errors.to_compile_error().into();
+    }
+
+    let variants: Vec<&syn::Ident> = data_enum
+        .variants
+        .iter()
+        .map(|variant| &variant.ident)
+        .collect();
+
+    // The `#[cfg(...)]` attributes of the variants are placed on every item, statement,
+    // or match arm generated for them so that the variants that are configured out are skipped.
+    let cfgs: Vec<proc_macro2::TokenStream> = data_enum
+        .variants
+        .iter()
+        .map(|variant| {
+            let cfg_attrs = variant
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("cfg"));
+            quote! { #(#cfg_attrs)* }
+        })
+        .collect();
+
+    // Originally, the signature of this function was
+    //
+    // ```
+    // #vis fn try_into_const_type_instance<T>
+    // (
+    //     self
+    // ) -> Option<#const_type_ident<{T::VALUE as usize}>>
+    // where
+    //     T: ::optionally_const::Const<#ident>,
+    // ```
+    let discriminant_variants = variants.iter();
+    let each_const_variants = variants.iter();
+    let from_discriminant_variants = variants.iter();
+    let valid_discriminant_variants = variants.iter();
+    // The impls of the trait that restricts the const parameter to the discriminants of
+    // the variants are generated unconditionally because the impls on the const types
+    // that can't handle other parameterizations are bounded by it as well.
+    let valid_discriminant_impls: proc_macro2::TokenStream = quote! {
+        #(
+            #cfgs
+            impl ::optionally_const::hidden::ValidDiscriminant<{#ident::#valid_discriminant_variants as usize}>
+                for #ident
+            {
+            }
+        )*
+    };
+
+    // The method is skipped with the `no_try_into` option to keep the inherent namespace
+    // of the enum minimal.
+    let try_into_fn: Option<proc_macro2::TokenStream> = (!no_try_into).then(|| {
+        quote! {
+            impl #ident {
+                #[doc =
+                    concat!(
+                        "Converts the enum variant into a [const type] instance.\n\
+                        \n\
+                        This is a code-generated function that was derived with the \
+                        [`#[derive(", stringify!(FieldlessEnumConstType), ")]`]\
+                        (::optionally_const::", stringify!(FieldlessEnumConstType),") \
+                        derive macro.\n\
+                        \n\
+                        This function is supposed to be parameterized by the enum variant's discriminants \
+                        converted to a `usize`.\n\
+                        \n\
+                        For example, `", stringify!(try_into_const_type_instance), "::<{",stringify!(#ident),"::Variant as usize}>()`.\n\
+                        \n\
+                        # Errors\n\
+                        \n\
+                        This function returns the original enum variant wrapped in [`Err`] if the \
+                        discriminant of the enum variant does not match the discriminant of the const type instance.\n\
+                        \n\
+                        This function is defined on the enum rather than implemented as a trait method \
+                        because at the time of writing this code, it's impossible to make the trait method `const`.\n\
+                        \n\
+                        [const type]: https://github.com/JohnScience/optionally_const/tree/main/optionally_const#const-type"
+                )]
+                #vis const fn try_into_const_type_instance<const DISCRIMINANT: usize>
+                (
+                    self
+                ) -> ::core::result::Result<#const_type_ident<DISCRIMINANT>, Self>
+                where
+                    #ident: ::optionally_const::hidden::ValidDiscriminant<DISCRIMINANT>,
+                    #(#const_type_where_predicates,)*
+                {
+                    if self as usize == DISCRIMINANT {
+                        Ok(#const_type_ident::<DISCRIMINANT>)
+                    } else {
+                        Err(self)
+                    }
+                }
+            }
+        }
+    });
+
+    let try_into_const_type_instance_impls_on_enum: proc_macro2::TokenStream = quote! {
+        #valid_discriminant_impls
+
+        #try_into_fn
+
+        impl #ident {
+            #[doc =
+                concat!(
+                    "Returns the number of bits needed to store the discriminant of any variant of \
+                    [`",stringify!(#ident), "`].\n\
+                    \n\
+                    This is a code-generated function that was derived with the \
+                    [`#[derive(", stringify!(FieldlessEnumConstType), ")]`]\
+                    (::optionally_const::", stringify!(FieldlessEnumConstType),") \
+                    derive macro.\n\
+                    \n\
+                    The result is computed from the largest discriminant converted to a `usize` \
+                    and is at least `1`, which makes it suitable for sizing bitfields that pack the enum."
+            )]
+            #[must_use]
+            #vis const fn discriminant_bits() -> u32 {
+                // The discriminants are compared in separate statements rather than collected
+                // into an array because array elements can't have `#[cfg(...)]` attributes.
+                let mut max_discriminant: usize = 0;
+                #(
+                    #cfgs
+                    {
+                        let discriminant: usize = #ident::#discriminant_variants as usize;
+                        if discriminant > max_discriminant {
+                            max_discriminant = discriminant;
+                        }
+                    }
+                )*
+
+                if max_discriminant == 0 {
+                    1
+                } else {
+                    usize::BITS - max_discriminant.leading_zeros()
+                }
+            }
+
+            #[doc =
+                concat!(
+                    "Converts a discriminant converted to a `usize` back into the variant of [`",stringify!(#ident), "`].\n\
+                    \n\
+                    This is a code-generated function that was derived with the \
+                    [`#[derive(", stringify!(FieldlessEnumConstType), ")]`]\
+                    (::optionally_const::", stringify!(FieldlessEnumConstType),") \
+                    derive macro.\n\
+                    \n\
+                    This is the runtime counterpart of [`", stringify!(try_into_const_type_instance), "`](Self::", stringify!(try_into_const_type_instance), "). \
+                    It returns [`None`] if no variant has the given discriminant."
+            )]
+            #[must_use]
+            #vis const fn from_discriminant(d: usize) -> ::core::option::Option<Self> {
+                match d {
+                    #(
+                        #cfgs
+                        discriminant if discriminant == #ident::#from_discriminant_variants as usize => {
+                            ::core::option::Option::Some(#ident::#from_discriminant_variants)
+                        }
+                    )*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            #[doc =
+                concat!(
+                    "Calls `f` with every variant of [`",stringify!(#ident), "`] in the order of declaration.\n\
+                    \n\
+                    This is a code-generated function that was derived with the \
+                    [`#[derive(", stringify!(FieldlessEnumConstType), ")]`]\
+                    (::optionally_const::", stringify!(FieldlessEnumConstType),") \
+                    derive macro.\n\
+                    \n\
+                    The const types of the variants are distinct types, so they can't be iterated over. \
+                    Instead, this function lets the callers build their own per-variant dispatch."
+            )]
+            #vis fn each_const<F: FnMut(#ident)>(mut f: F) {
+                #(
+                    #cfgs
+                    f(#ident::#each_const_variants);
+                )*
+            }
+        }
+    };
+
+    // The strategy is generated by a macro from the `optionally_const` crate so that it's
+    // emitted only when its `proptest` feature is enabled.
+    let proptest_impls: proc_macro2::TokenStream = quote! {
+        ::optionally_const::hidden::fieldless_enum_proptest_impls! {
+            #vis #ident, [#(#cfgs #variants),*]
+        }
+    };
+
+    let const_type_impls = const_type_syntaxes
+        .into_iter()
+        .map(|const_type_syntax| {
+            const_type_impls(&vis, &ident, &variants, &cfgs, const_type_syntax)
+        });
+
+    let output: proc_macro2::TokenStream = quote! {
+        #try_into_const_type_instance_impls_on_enum
+        #proptest_impls
+        #(#const_type_impls)*
+    };
+
+    let output: TokenStream = output.into();
+
+    output
+}
+
+// Ranks the visibilities that can be ordered without resolving paths, from the least
+// to the most visible. `pub(super)` and `pub(in path)` can't be ranked.
+fn visibility_rank(vis: &syn::Visibility) -> Option<u8> {
+    match vis {
+        syn::Visibility::Inherited => Some(0),
+        syn::Visibility::Restricted(restricted) if restricted.path.is_ident("self") => Some(0),
+        syn::Visibility::Restricted(restricted) if restricted.path.is_ident("crate") => Some(1),
+        syn::Visibility::Restricted(_) => None,
+        syn::Visibility::Public(_) => Some(2),
+    }
+}
+
+// Generates a const type for the enum together with its impls.
+fn const_type_impls(
+    vis: &syn::Visibility,
+    ident: &syn::Ident,
+    variants: &[&syn::Ident],
+    cfgs: &[proc_macro2::TokenStream],
+    const_type_syntax: ConstTypeSyntax,
+) -> proc_macro2::TokenStream {
+    let ConstTypeSyntax {
+        attrs: const_type_attrs,
+        vis: const_type_vis,
+        name: const_type_ident,
+        where_predicates,
+        options: ConstTypeOptions {
+            align,
+            doc,
+            from_str,
+            display,
+            cross_eq,
+            no_try_into: _,
+            stable_discriminants: _,
+        },
+    } = const_type_syntax;
 
+    // The where clause is emitted on the const type and all of its impls.
+    // If there are no predicates, it's printed as nothing at all.
+    let where_clause = syn::WhereClause {
+        where_token: Default::default(),
+        predicates: where_predicates.iter().cloned().collect(),
+    };
+    let where_predicates = &where_clause.predicates;
+
+    // The const type inherits the visibility of the enum unless it's overridden.
+    let enum_vis: &syn::Visibility = vis;
+    let vis: &syn::Visibility = if matches!(const_type_vis, syn::Visibility::Inherited) {
+        enum_vis
+    } else {
+        &const_type_vis
+    };
+
+    // An associated type can't be less visible than the impl [E0446], so `ConstVal` is
+    // implemented only if the const type is at most as visible as the enum.
+    let has_const_val: bool = match (visibility_rank(vis), visibility_rank(enum_vis)) {
+        (Some(const_type_rank), Some(enum_rank)) => const_type_rank <= enum_rank,
+        // `pub(super)` and `pub(in path)` are compared only with the same visibility.
+        _ => quote!(#vis).to_string() == quote!(#enum_vis).to_string(),
+    };
+
+    let variants = variants.iter();
+    let cfgs = cfgs.iter();
+
+    let repr_align: Option<proc_macro2::TokenStream> =
+        align.map(|align| quote! { #[repr(align(#align))] });
+
+    // The custom documentation is separated from the generated one by an empty line
+    // so that they are rendered as separate paragraphs.
+    let custom_doc: Option<proc_macro2::TokenStream> = doc.map(|doc| {
+        quote! {
+            #[doc = #doc]
+            #[doc = ""]
+        }
+    });
+
+    let as_enum_variants = variants.clone();
+    let as_enum_cfgs = cfgs.clone();
     let const_type_defn: proc_macro2::TokenStream = quote! {
+        #custom_doc
         #[doc =
             concat!(
                 "A [const type] for the [fieldless enum] [`",stringify!(#ident), "`].\n\
@@ -154,79 +696,299 @@ pub fn derive_fieldless_enum_const_type(input: TokenStream) -> TokenStream {
         #(
             #const_type_attrs
         )*
-        #vis struct #const_type_ident<const DISCRIMINANT: usize>;
+        #repr_align
+        #vis struct #const_type_ident<const DISCRIMINANT: usize> #where_clause;
+
+        // The impls are restricted to the discriminants of the variants, so that
+        // an instance representing no variant can't be created or converted into the enum.
+        impl<const DISCRIMINANT: usize> ::core::default::Default for #const_type_ident<DISCRIMINANT>
+        where
+            #ident: ::optionally_const::hidden::ValidDiscriminant<DISCRIMINANT>,
+            #where_predicates
+        {
+            fn default() -> Self {
+                #const_type_ident
+            }
+        }
+
+        impl<const DISCRIMINANT: usize> #const_type_ident<DISCRIMINANT>
+        where
+            #ident: ::optionally_const::hidden::ValidDiscriminant<DISCRIMINANT>,
+            #where_predicates
+        {
+            /// Returns the discriminant of the enum variant represented by this const type.
+            #[must_use]
+            #vis const fn discriminant(&self) -> usize {
+                DISCRIMINANT
+            }
+
+            /// Returns the enum variant represented by this const type.
+            ///
+            /// Unlike [`OptionallyConst::into_value`](::optionally_const::OptionallyConst::into_value),
+            /// this method is `const`.
+            #[must_use]
+            #vis const fn as_enum(&self) -> #ident {
+                Self::value()
+            }
+
+            /// Returns the enum variant represented by this const type.
+            ///
+            /// Unlike [`Const::value`](::optionally_const::Const::value), this function is `const`.
+            #[must_use]
+            #vis const fn value() -> #ident {
+                match DISCRIMINANT {
+                    #(
+                        #as_enum_cfgs
+                        discriminant if discriminant == #ident::#as_enum_variants as usize => {
+                            #ident::#as_enum_variants
+                        }
+                    )*
+                    _ => unreachable!(),
+                }
+            }
+        }
     };
 
     let const_impls: proc_macro2::TokenStream = {
         let variants = variants.clone();
+        let cfgs = cfgs.clone();
         quote! {
             #(
-                impl ::optionally_const::Const<#ident> for #const_type_ident<{#ident::#variants as usize}> {
+                #cfgs
+                impl ::optionally_const::sealed::Sealed<#ident> for #const_type_ident<{#ident::#variants as usize}>
+                #where_clause
+                {
+                }
+
+                #cfgs
+                impl ::optionally_const::Const<#ident> for #const_type_ident<{#ident::#variants as usize}>
+                #where_clause
+                {
                     const VALUE: #ident = #ident::#variants;
                 }
             )*
         }
     };
 
-    // Originally, the signature of this function was
-    //
-    // ```
-    // #vis fn try_into_const_type_instance<T>
-    // (
-    //     self
-    // ) -> Option<#const_type_ident<{T::VALUE as usize}>>
-    // where
-    //     T: ::optionally_const::Const<#ident>,
-    // ```
-    let try_into_const_type_instance_impls_on_enum: proc_macro2::TokenStream = quote! {
-        impl #ident {
+    let const_val_impls: Option<proc_macro2::TokenStream> = has_const_val.then(|| {
+        let variants = variants.clone();
+        let cfgs = cfgs.clone();
+        quote! {
+            #(
+                #cfgs
+                impl ::optionally_const::ConstVal for #const_type_ident<{#ident::#variants as usize}>
+                #where_clause
+                {
+                    type Value = #ident;
+                }
+            )*
+        }
+    });
+
+    let from_impls: proc_macro2::TokenStream = {
+        let variants = variants.clone();
+        let cfgs = cfgs.clone();
+        quote! {
+            #(
+                #cfgs
+                impl ::core::convert::From<#const_type_ident<{#ident::#variants as usize}>> for #ident
+                #where_clause
+                {
+                    fn from(_const_type: #const_type_ident<{#ident::#variants as usize}>) -> #ident {
+                        #ident::#variants
+                    }
+                }
+            )*
+        }
+    };
+
+    // Each parameterization of the const type is a separate type, so a runtime discriminant
+    // can be converted only into an enum that wraps all of them.
+    let any_defn: proc_macro2::TokenStream = {
+        let any_ident: syn::Ident = format_ident!("{}Any", const_type_ident);
+        let defn_variants = variants.clone();
+        let defn_cfgs = cfgs.clone();
+        let try_from_variants = variants.clone();
+        let try_from_cfgs = cfgs.clone();
+        quote! {
             #[doc =
                 concat!(
-                    "Converts the enum variant into a [const type] instance.\n\
+                    "A runtime enum over the parameterizations of [`",stringify!(#const_type_ident), "`] \
+                    that represent the variants of [`",stringify!(#ident), "`].\n\
                     \n\
-                    This is a code-generated function that was derived with the \
+                    This is a code-generated type that was derived with the \
                     [`#[derive(", stringify!(FieldlessEnumConstType), ")]`]\
                     (::optionally_const::", stringify!(FieldlessEnumConstType),") \
                     derive macro.\n\
                     \n\
-                    This function is supposed to be parameterized by the enum variant's discriminants \
-                    converted to a `usize`.\n\
-                    \n\
-                    For example, `", stringify!(try_into_const_type_instance), "::<{",stringify!(#ident),"::Variant as usize}>()`.\n\
-                    \n\
-                    # Errors\n\
-                    \n\
-                    This function returns the original enum variant wrapped in [`Err`] if the \
-                    discriminant of the enum variant does not match the discriminant of the const type instance.\n\
-                    \n\
-                    This function is defined on the enum rather than implemented as a trait method \
-                    because at the time of writing this code, it's impossible to make the trait method `const`.\n\
-                    \n\
-                    [const type]: https://github.com/JohnScience/optionally_const/tree/main/optionally_const#const-type"
+                    It's obtained from a runtime discriminant with [`TryFrom<usize>`] and bridges \
+                    the runtime discriminants to the const types by matching on the variants."
             )]
-            #vis const fn try_into_const_type_instance<const DISCRIMINANT: usize>
-            (
-                self
-            ) -> ::core::result::Result<#const_type_ident<DISCRIMINANT>, Self>
+            #[derive(Clone, Copy, PartialEq)]
+            #vis enum #any_ident #where_clause {
+                #(
+                    #defn_cfgs
+                    #[allow(missing_docs)]
+                    #defn_variants(#const_type_ident<{#ident::#defn_variants as usize}>),
+                )*
+            }
+
+            impl ::core::convert::TryFrom<usize> for #any_ident #where_clause {
+                type Error = usize;
+
+                fn try_from(discriminant: usize) -> ::core::result::Result<Self, usize> {
+                    match discriminant {
+                        #(
+                            #try_from_cfgs
+                            d if d == #ident::#try_from_variants as usize => {
+                                ::core::result::Result::Ok(#any_ident::#try_from_variants(#const_type_ident))
+                            }
+                        )*
+                        _ => ::core::result::Result::Err(discriminant),
+                    }
+                }
+            }
+        }
+    };
+
+    // The string is accepted only if it's the name of the variant represented
+    // by the parameterization of the const type.
+    let from_str_impl: Option<proc_macro2::TokenStream> = from_str.then(|| {
+        let parsed_variants = variants.clone();
+        let parsed_cfgs = cfgs.clone();
+        let expected_variants = variants.clone();
+        let expected_cfgs = cfgs.clone();
+        quote! {
+            impl<const DISCRIMINANT: usize> ::core::str::FromStr for #const_type_ident<DISCRIMINANT>
             where
-                #const_type_ident<DISCRIMINANT>: ::optionally_const::Const<#ident>,
+                #ident: ::optionally_const::hidden::ValidDiscriminant<DISCRIMINANT>,
+                #where_predicates
             {
-                if self as usize == DISCRIMINANT {
-                    Ok(#const_type_ident::<DISCRIMINANT>)
-                } else {
-                    Err(self)
+                type Err = ::optionally_const::ParseVariantError;
+
+                fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                    let matches: bool = match s {
+                        #(
+                            #parsed_cfgs
+                            stringify!(#parsed_variants) => #ident::#parsed_variants as usize == DISCRIMINANT,
+                        )*
+                        _ => false,
+                    };
+                    if matches {
+                        Ok(#const_type_ident)
+                    } else {
+                        let expected: &'static [&'static str] = match DISCRIMINANT {
+                            #(
+                                #expected_cfgs
+                                discriminant if discriminant == #ident::#expected_variants as usize => {
+                                    &[stringify!(#expected_variants)]
+                                }
+                            )*
+                            _ => unreachable!(),
+                        };
+                        Err(::optionally_const::ParseVariantError { expected })
+                    }
                 }
             }
         }
+    });
+
+    let display_impl: Option<proc_macro2::TokenStream> = display.then(|| {
+        quote! {
+            impl<const DISCRIMINANT: usize> ::core::fmt::Display for #const_type_ident<DISCRIMINANT>
+            where
+                #ident: ::core::fmt::Display,
+                #ident: ::optionally_const::hidden::ValidDiscriminant<DISCRIMINANT>,
+                #where_predicates
+            {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    ::core::fmt::Display::fmt(&self.as_enum(), f)
+                }
+            }
+        }
+    });
+
+    let cross_eq_impl: Option<proc_macro2::TokenStream> = cross_eq.then(|| {
+        quote! {
+            impl<const D1: usize, const D2: usize> ::core::cmp::PartialEq<#const_type_ident<D2>>
+                for #const_type_ident<D1>
+            #where_clause
+            {
+                fn eq(&self, _other: &#const_type_ident<D2>) -> bool {
+                    D1 == D2
+                }
+            }
+        }
+    });
+
+    // The const type is compared with the enum by the discriminant, so the impls
+    // are generic over all parameterizations of the const type.
+    let partial_eq_impls: proc_macro2::TokenStream = quote! {
+
+        impl<const DISCRIMINANT: usize> ::core::cmp::PartialEq<#ident> for #const_type_ident<DISCRIMINANT>
+        #where_clause
+        {
+            fn eq(&self, other: &#ident) -> bool {
+                *other as usize == DISCRIMINANT
+            }
+        }
+
+        impl<const DISCRIMINANT: usize> ::core::cmp::PartialEq<#const_type_ident<DISCRIMINANT>> for #ident
+        #where_clause
+        {
+            fn eq(&self, _other: &#const_type_ident<DISCRIMINANT>) -> bool {
+                *self as usize == DISCRIMINANT
+            }
+        }
+    };
+
+    // The impls are generated by a macro from the `optionally_const` crate so that they
+    // are emitted only when its `serde` feature is enabled.
+    let serde_impls: proc_macro2::TokenStream = {
+        let variants = variants.clone();
+        let cfgs = cfgs.clone();
+        quote! {
+            ::optionally_const::hidden::fieldless_enum_serde_impls! {
+                #ident, #const_type_ident, [#(#cfgs #variants),*], where [#where_predicates]
+            }
+        }
+    };
+
+    // Same as above, but for the `defmt` feature.
+    let defmt_impls: proc_macro2::TokenStream = {
+        let variants = variants.clone();
+        let cfgs = cfgs.clone();
+        quote! {
+            ::optionally_const::hidden::fieldless_enum_defmt_impls! {
+                #ident, #const_type_ident, [#(#cfgs #variants),*], where [#where_predicates]
+            }
+        }
+    };
+
+    // Same as above, but for the `bytemuck` feature.
+    let bytemuck_impls: proc_macro2::TokenStream = quote! {
+        ::optionally_const::hidden::fieldless_enum_bytemuck_impls! {
+            #ident, #const_type_ident, where [#where_predicates]
+        }
+    };
+
+    // Same as above, but for the `arbitrary` feature.
+    let arbitrary_impls: proc_macro2::TokenStream = quote! {
+        ::optionally_const::hidden::fieldless_enum_arbitrary_impls! {
+            #ident, #const_type_ident, where [#where_predicates]
+        }
     };
 
     let optionally_const_impls: proc_macro2::TokenStream = quote! {
         #(
-            impl ::optionally_const::OptionallyConst<#ident> for #const_type_ident<{#ident::#variants as usize}> {
+            #cfgs
+            impl ::optionally_const::OptionallyConst<#ident> for #const_type_ident<{#ident::#variants as usize}>
+            #where_clause
+            {
                 const MAYBE_CONST: Option<#ident> = Some(#ident::#variants);
 
                 fn into_value(self) -> #ident {
-                    #ident::#variants
+                    Self::value()
                 }
 
                 fn try_from_value(value: #ident) -> Result<Self, #ident> {
@@ -240,14 +1002,371 @@ pub fn derive_fieldless_enum_const_type(input: TokenStream) -> TokenStream {
         )*
     };
 
-    let output: proc_macro2::TokenStream = quote! {
-        #try_into_const_type_instance_impls_on_enum
+    quote! {
         #const_type_defn
         #const_impls
+        #const_val_impls
         #optionally_const_impls
+        #from_impls
+        #any_defn
+        #from_str_impl
+        #display_impl
+        #cross_eq_impl
+        #partial_eq_impls
+        #serde_impls
+        #defmt_impls
+        #bytemuck_impls
+        #arbitrary_impls
+    }
+}
+
+/// Derives the implementations of the [`Const`] and [`OptionallyConst`] traits
+/// for a unit struct that represents a constant value.
+///
+/// The type and the value of the constant are specified with the
+/// `#[const_value(ty = Type, value = expr)]` attribute. The value must be a constant expression.
+///
+/// The unit struct must derive the [`Clone`], [`Copy`], and [`PartialEq`] traits, and
+/// the type of the constant must implement [`PartialEq`].
+///
+/// # Example
+///
+/// ```rust
+/// use optionally_const::{Const, OptionallyConst};
+/// use optionally_const_macros::ConstValue;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum MyEnum {
+///     A,
+///     B,
+/// }
+///
+/// #[derive(ConstValue, Clone, Copy, PartialEq)]
+/// #[const_value(ty = MyEnum, value = MyEnum::A)]
+/// struct MyEnumAConstType;
+///
+/// assert_eq!(<MyEnumAConstType as Const<MyEnum>>::VALUE, MyEnum::A);
+/// assert_eq!(
+///     <MyEnumAConstType as OptionallyConst<MyEnum>>::MAYBE_CONST,
+///     Some(MyEnum::A),
+/// );
+/// ```
+///
+/// [`Const`]: https://docs.rs/optionally_const/latest/optionally_const/trait.Const.html
+/// [`OptionallyConst`]: https://docs.rs/optionally_const/latest/optionally_const/trait.OptionallyConst.html
+#[allow(clippy::missing_panics_doc)]
+#[proc_macro_derive(ConstValue, attributes(const_value))]
+pub fn derive_const_value(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+
+    let DeriveInput {
+        attrs,
+        vis,
+        ident,
+        generics: _no_generics,
+        data,
+    } = input;
+
+    let syn::Data::Struct(data_struct) = data else {
+        panic!("#[derive(ConstValue)] can only be used on unit structs.");
     };
+    assert!(
+        matches!(data_struct.fields, syn::Fields::Unit),
+        "#[derive(ConstValue)] can only be used on unit structs."
+    );
 
-    let output: TokenStream = output.into();
+    let const_value_attr: &syn::Attribute = attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("const_value"))
+        .unwrap_or_else(|| {
+            panic!("Expected #[const_value(ty = Type, value = expr)] attribute");
+        });
 
-    output
+    let mut ty: Option<syn::Type> = None;
+    let mut value: Option<syn::Expr> = None;
+    const_value_attr
+        .parse_nested_meta(|meta| {
+            if meta.path.is_ident("ty") {
+                ty = Some(meta.value()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("value") {
+                value = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("expected `ty` or `value`"))
+            }
+        })
+        .unwrap_or_else(|err| {
+            panic!("Failed to parse #[const_value(ty = Type, value = expr)] attribute: {err}");
+        });
+
+    let ty: syn::Type =
+        ty.unwrap_or_else(|| panic!("Expected `ty = Type` in #[const_value(...)] attribute"));
+    let value: syn::Expr =
+        value.unwrap_or_else(|| panic!("Expected `value = expr` in #[const_value(...)] attribute"));
+
+    let output: proc_macro2::TokenStream = quote! {
+        impl ::optionally_const::sealed::Sealed<#ty> for #ident {}
+
+        impl ::optionally_const::Const<#ty> for #ident {
+            const VALUE: #ty = #value;
+        }
+
+        impl ::optionally_const::ConstVal for #ident {
+            type Value = #ty;
+        }
+
+        impl #ident {
+            /// Returns the constant represented by the type.
+            ///
+            /// Unlike [`Const::value`](::optionally_const::Const::value), this function is `const`.
+            #[must_use]
+            #vis const fn value() -> #ty {
+                <Self as ::optionally_const::Const<#ty>>::VALUE
+            }
+        }
+
+        impl ::optionally_const::OptionallyConst<#ty> for #ident {
+            const MAYBE_CONST: Option<#ty> = Some(<Self as ::optionally_const::Const<#ty>>::VALUE);
+
+            fn into_value(self) -> #ty {
+                Self::value()
+            }
+
+            fn try_from_value(value: #ty) -> Result<Self, #ty> {
+                if value == <Self as ::optionally_const::Const<#ty>>::VALUE {
+                    Ok(#ident)
+                } else {
+                    Err(value)
+                }
+            }
+        }
+    };
+
+    output.into()
+}
+
+/// Derives a [const type] for a newtype, i.e. a tuple struct with a single field, as well as
+/// the implementations of the [`Const`] and [`OptionallyConst`] traits for it.
+///
+/// For a newtype `Name(Inner)`, the const type is named `ConstTypeName` and is parameterized
+/// by a const parameter of type `Inner`, e.g. `ConstTypeEnabled<true>` represents `Enabled(true)`.
+/// Consequently, `Inner` must be a type that can be used as a type of a const generic parameter,
+/// such as `bool`, `char`, or an integer type. The generated const type has the same visibility
+/// as the newtype.
+///
+/// The newtype must derive the [`Clone`], [`Copy`], and [`PartialEq`] traits, so that
+/// the blanket impl of [`OptionallyConst`] covers its runtime values.
+/// Generic newtypes are not supported.
+///
+/// # Example
+///
+/// ```rust
+/// use optionally_const::{Const, OptionallyConst};
+/// use optionally_const_macros::OptionallyConstNewtype;
+///
+/// #[derive(OptionallyConstNewtype, Debug, Clone, Copy, PartialEq)]
+/// struct Enabled(bool);
+///
+/// fn is_enabled<T: OptionallyConst<Enabled>>(enabled: T) -> bool {
+///     let Enabled(enabled) = enabled.into_value();
+///     enabled
+/// }
+///
+/// assert!(is_enabled(Enabled(true)));
+/// assert!(is_enabled(ConstTypeEnabled::<true>::new()));
+/// assert_eq!(<ConstTypeEnabled<false> as Const<Enabled>>::VALUE, Enabled(false));
+/// ```
+///
+/// [const type]: https://github.com/JohnScience/optionally_const/tree/main/optionally_const#const-type
+/// [`Const`]: https://docs.rs/optionally_const/latest/optionally_const/trait.Const.html
+/// [`OptionallyConst`]: https://docs.rs/optionally_const/latest/optionally_const/trait.OptionallyConst.html
+#[allow(clippy::missing_panics_doc)]
+#[proc_macro_derive(OptionallyConstNewtype)]
+pub fn derive_optionally_const_newtype(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+
+    let DeriveInput {
+        attrs: _attrs,
+        vis,
+        ident,
+        generics,
+        data,
+    } = input;
+
+    if !generics.params.is_empty() {
+        return syn::Error::new_spanned(
+            &generics,
+            "#[derive(OptionallyConstNewtype)] doesn't support generic newtypes yet",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let syn::Data::Struct(data_struct) = data else {
+        panic!("#[derive(OptionallyConstNewtype)] can only be used on newtypes.");
+    };
+    let syn::Fields::Unnamed(fields) = data_struct.fields else {
+        panic!("#[derive(OptionallyConstNewtype)] can only be used on newtypes.");
+    };
+    assert_eq!(
+        fields.unnamed.len(),
+        1,
+        "#[derive(OptionallyConstNewtype)] can only be used on newtypes."
+    );
+    let inner_ty: &syn::Type = &fields.unnamed[0].ty;
+
+    let const_type_ident: syn::Ident = format_ident!("ConstType{}", ident);
+
+    let output: proc_macro2::TokenStream = quote! {
+        #[doc =
+            concat!(
+                "A [const type] for the newtype [`",stringify!(#ident), "`].\n\
+                \n\
+                This is a code-generated type that was derived with the \
+                [`#[derive(", stringify!(OptionallyConstNewtype), ")]`]\
+                (::optionally_const::", stringify!(OptionallyConstNewtype),") \
+                derive macro.\n\
+                \n\
+                For example, `", stringify!(#const_type_ident), "<VAL>` represents `",
+                stringify!(#ident), "(VAL)`.\n\
+                \n\
+                [const type]: https://github.com/JohnScience/optionally_const/tree/main/optionally_const#const-type"
+        )]
+        #[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+        #vis struct #const_type_ident<const VAL: #inner_ty>;
+
+        impl<const VAL: #inner_ty> #const_type_ident<VAL> {
+            /// Creates an instance of the const type.
+            #vis const fn new() -> Self {
+                Self
+            }
+
+            /// Returns the newtype represented by this const type.
+            ///
+            /// Unlike [`Const::value`](::optionally_const::Const::value), this function is `const`.
+            #[must_use]
+            #vis const fn value() -> #ident {
+                #ident(VAL)
+            }
+        }
+
+        impl<const VAL: #inner_ty> ::optionally_const::sealed::Sealed<#ident> for #const_type_ident<VAL> {}
+
+        impl<const VAL: #inner_ty> ::optionally_const::Const<#ident> for #const_type_ident<VAL> {
+            const VALUE: #ident = #ident(VAL);
+        }
+
+        impl<const VAL: #inner_ty> ::optionally_const::ConstVal for #const_type_ident<VAL> {
+            type Value = #ident;
+        }
+
+        impl<const VAL: #inner_ty> ::optionally_const::OptionallyConst<#ident> for #const_type_ident<VAL> {
+            const MAYBE_CONST: Option<#ident> = Some(#ident(VAL));
+
+            fn into_value(self) -> #ident {
+                Self::value()
+            }
+
+            fn try_from_value(value: #ident) -> Result<Self, #ident> {
+                if value == #ident(VAL) {
+                    Ok(#const_type_ident)
+                } else {
+                    Err(value)
+                }
+            }
+        }
+    };
+
+    output.into()
+}
+
+/// Expands to the const type representing a literal, with the carrier selected by the
+/// kind and the suffix of the literal.
+///
+/// Used by `const_type_instance!`, which can't inspect the suffix of a literal on its own.
+/// The input is the path to the `optionally_const` crate (`$crate` at the call site),
+/// followed by a comma and the literal, e.g. `$crate, 42u32`. An integer literal without
+/// a suffix is taken to be `i32`, just like in a `let` binding without a type annotation.
+/// A character literal produces a `ConstTypeChar`.
+#[doc(hidden)]
+#[proc_macro]
+pub fn __const_type_instance_literal(input: TokenStream) -> TokenStream {
+    let input = proc_macro2::TokenStream::from(input);
+    let mut tokens = input.into_iter();
+
+    // The path is passed through untouched because `$crate` can't be parsed by `syn`.
+    let crate_path: proc_macro2::TokenStream = tokens
+        .by_ref()
+        .take_while(|tt| !matches!(tt, proc_macro2::TokenTree::Punct(p) if p.as_char() == ','))
+        .collect();
+    let literal: proc_macro2::TokenStream = tokens.collect();
+
+    let expr = match syn::parse2::<syn::Expr>(literal.clone()) {
+        Ok(expr) => expr,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    let mut lit_expr = &expr;
+    let mut is_negated = false;
+    loop {
+        lit_expr = match lit_expr {
+            syn::Expr::Group(group) => &group.expr,
+            syn::Expr::Unary(syn::ExprUnary {
+                op: syn::UnOp::Neg(_),
+                expr,
+                ..
+            }) => {
+                is_negated = true;
+                expr
+            }
+            _ => break,
+        };
+    }
+
+    let carrier = match lit_expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit),
+            ..
+        }) => match lit.suffix() {
+            "" | "i32" => "ConstTypeI32",
+            "i8" => "ConstTypeI8",
+            "i16" => "ConstTypeI16",
+            "i64" => "ConstTypeI64",
+            "i128" => "ConstTypeI128",
+            "isize" => "ConstTypeIsize",
+            "u8" => "ConstTypeU8",
+            "u16" => "ConstTypeU16",
+            "u32" => "ConstTypeU32",
+            "u64" => "ConstTypeU64",
+            "u128" => "ConstTypeU128",
+            "usize" => "ConstTypeUsize",
+            suffix => {
+                return syn::Error::new_spanned(
+                    lit,
+                    format!("`const_type_instance!` doesn't support the `{suffix}` suffix"),
+                )
+                .to_compile_error()
+                .into();
+            }
+        },
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Char(_),
+            ..
+        }) if !is_negated => "ConstTypeChar",
+        _ => {
+            return syn::Error::new_spanned(
+                &expr,
+                "`const_type_instance!` supports only integer and character literals here",
+            )
+            .to_compile_error()
+            .into();
+        }
+    };
+    let carrier = format_ident!("{}", carrier);
+
+    quote! {
+        #crate_path::hidden::#carrier::<{ #literal }>
+    }
+    .into()
 }
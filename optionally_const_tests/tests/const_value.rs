@@ -0,0 +1,97 @@
+use optionally_const::{
+    Const, ConstVal, ConstValue, OptionallyConst, impl_const, impl_optionally_const_via_const,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum MyEnum {
+    A,
+    B,
+}
+
+#[derive(ConstValue, Debug, Clone, Copy, PartialEq)]
+#[const_value(ty = MyEnum, value = MyEnum::A)]
+struct MyEnumAConstType;
+
+#[derive(ConstValue, Debug, Clone, Copy, PartialEq)]
+#[const_value(ty = bool, value = true)]
+struct TrueConstType;
+
+fn print_my_enum<T: OptionallyConst<MyEnum>>(value: T) -> Option<MyEnum> {
+    if let Some(value) = T::MAYBE_CONST {
+        println!("value is const: {value:?}");
+    } else {
+        let value: MyEnum = value.into_value();
+        println!("value is not const: {value:?}");
+    };
+    T::MAYBE_CONST
+}
+
+fn test_const() {
+    assert_eq!(<MyEnumAConstType as Const<MyEnum>>::VALUE, MyEnum::A);
+    let value: bool = TrueConstType.into_value();
+    assert!(value);
+}
+
+fn test_inherent_value() {
+    const A: MyEnum = MyEnumAConstType::value();
+    assert_eq!(A, MyEnum::A);
+    let value: MyEnum = MyEnumAConstType.into_value();
+    assert_eq!(value, A);
+}
+
+fn test_optionally_const() {
+    assert_eq!(print_my_enum(MyEnumAConstType), Some(MyEnum::A));
+    assert_eq!(print_my_enum(MyEnum::B), None);
+
+    let value: MyEnum = MyEnumAConstType.into_value();
+    assert_eq!(value, MyEnum::A);
+}
+
+fn test_try_from_value() {
+    assert_eq!(
+        MyEnumAConstType::try_from_value(MyEnum::A),
+        Ok(MyEnumAConstType)
+    );
+    assert_eq!(MyEnumAConstType::try_from_value(MyEnum::B), Err(MyEnum::B));
+    assert_eq!(TrueConstType::try_from_value(false), Err(false));
+}
+
+fn test_const_val() {
+    fn value_of<C: ConstVal>() -> C::Value {
+        C::VALUE
+    }
+
+    assert_eq!(value_of::<MyEnumAConstType>(), MyEnum::A);
+    assert!(value_of::<TrueConstType>());
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct MyEnumBConstType;
+
+struct Limits;
+
+impl_const! {
+    MyEnumBConstType: MyEnum = MyEnum::B;
+    Limits: u8 = u8::MAX;
+    Limits: (u8, u8) = (0, 10);
+}
+
+impl_optionally_const_via_const!(MyEnumBConstType: MyEnum);
+
+fn test_impl_const() {
+    assert_eq!(<MyEnumBConstType as Const<MyEnum>>::VALUE, MyEnum::B);
+    assert_eq!(print_my_enum(MyEnumBConstType), Some(MyEnum::B));
+    assert_eq!(MyEnumBConstType::try_from_value(MyEnum::A), Err(MyEnum::A));
+
+    assert_eq!(<Limits as Const<u8>>::VALUE, u8::MAX);
+    assert_eq!(<Limits as Const<(u8, u8)>>::value(), (0, 10));
+}
+
+fn main() {
+    test_const();
+    test_optionally_const();
+    test_try_from_value();
+    test_const_val();
+    test_impl_const();
+    test_inherent_value();
+}
@@ -1,9 +1,10 @@
-use optionally_const::{FieldlessEnumConstType, OptionallyConst};
+use optionally_const::{FieldlessEnumConstType, OptionallyConst, const_carrier_for};
 
 #[derive(FieldlessEnumConstType, Debug, Clone, Copy, PartialEq)]
 #[const_type(
-    #[derive(Clone, Copy, PartialEq)]
-    ConstTypeName
+    #[derive(Clone, Copy)]
+    ConstTypeName,
+    cross_eq,
 )]
 enum FieldlessEnum {
     A,
@@ -11,6 +12,219 @@ enum FieldlessEnum {
     C,
 }
 
+#[derive(FieldlessEnumConstType, Debug, Clone, Copy, PartialEq)]
+#[const_type(
+    #[derive(Clone, Copy, PartialEq)]
+    LargeConstTypeName
+)]
+enum LargeFieldlessEnum {
+    A,
+    B = 100,
+    C = 200,
+}
+
+#[derive(FieldlessEnumConstType, Debug, Clone, Copy, PartialEq)]
+#[const_type(
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    HashableConstTypeName
+)]
+enum HashableFieldlessEnum {
+    A,
+    B,
+}
+
+#[derive(FieldlessEnumConstType, Debug, Clone, Copy, PartialEq)]
+#[const_type(
+    #[derive(Debug, Clone, Copy)]
+    FlagConstTypeName,
+    cross_eq,
+)]
+enum FlagFieldlessEnum {
+    A = 1 << 0,
+    B = 1 << 1,
+    C = 1 << 2,
+}
+
+#[derive(FieldlessEnumConstType, Debug, Clone, Copy, PartialEq)]
+#[const_type(
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    ShapeConstType
+)]
+#[const_type(
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    ShapeMarker
+)]
+enum Shape {
+    Circle,
+    Square,
+}
+
+#[derive(FieldlessEnumConstType, Debug, Clone, Copy, PartialEq)]
+#[const_type(
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    AlignedConstType,
+    align(8),
+)]
+enum Aligned {
+    A,
+    B,
+}
+
+#[derive(FieldlessEnumConstType, Debug, Clone, Copy, PartialEq)]
+#[const_type(
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    DocumentedConstType,
+    doc = "The compile-time selected log level.",
+)]
+enum LogLevel {
+    Info,
+    Error,
+}
+
+#[derive(FieldlessEnumConstType, Debug, Clone, Copy, PartialEq)]
+#[const_type(
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    NonExhaustiveConstType
+)]
+#[non_exhaustive]
+enum NonExhaustive {
+    A,
+    B,
+}
+
+// `#[repr(...)]` attributes surround `#[const_type(...)]` to check that they are
+// not mistaken for it.
+#[derive(FieldlessEnumConstType, Debug, Clone, Copy, PartialEq)]
+#[repr(C)]
+#[const_type(
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    ReprCConstType
+)]
+enum ReprC {
+    A,
+    B = 10,
+}
+
+#[derive(FieldlessEnumConstType, Debug, Clone, Copy, PartialEq)]
+#[const_type(
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    ReprU8ConstType
+)]
+#[repr(u8)]
+enum ReprU8 {
+    A = 1,
+    B,
+}
+
+#[derive(FieldlessEnumConstType, Debug, Clone, Copy, PartialEq)]
+#[const_type(
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    ParsableConstType,
+    from_str,
+)]
+enum Parsable {
+    A,
+    B,
+}
+
+#[derive(FieldlessEnumConstType, Debug, Clone, Copy, PartialEq)]
+#[const_type(
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    DisplayableConstType,
+    display,
+)]
+enum Displayable {
+    A,
+    B,
+}
+
+impl std::fmt::Display for Displayable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Displayable::A => write!(f, "A"),
+            Displayable::B => write!(f, "B"),
+        }
+    }
+}
+
+#[derive(FieldlessEnumConstType, Debug, Clone, Copy, PartialEq)]
+#[const_type(
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    ConfiguredConstType,
+    from_str,
+)]
+enum Configured {
+    /// A variant that is always present.
+    A,
+    /// A variant that is present only with the `cfg_variant` feature.
+    #[cfg(feature = "cfg_variant")]
+    B,
+    #[cfg(any())]
+    Never,
+    C,
+}
+
+#[derive(FieldlessEnumConstType, Debug, Clone, Copy, PartialEq)]
+#[const_type(
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    BoundedConstType where usize: Copy, u8: Into<u32>,
+    from_str,
+)]
+enum Bounded {
+    A,
+    B,
+}
+
+#[derive(FieldlessEnumConstType, Debug, Clone, Copy, PartialEq)]
+#[const_type(
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    MinimalConstType,
+    no_try_into,
+)]
+enum Minimal {
+    A,
+    B,
+}
+
+#[derive(FieldlessEnumConstType, Debug, Clone, Copy, PartialEq)]
+#[const_type(
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    StableConstType,
+    stable_discriminants,
+)]
+enum Stable {
+    B = 1,
+    A = 0,
+}
+
+mod private {
+    use optionally_const::FieldlessEnumConstType;
+
+    #[derive(FieldlessEnumConstType, Debug, Clone, Copy, PartialEq)]
+    #[const_type(
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub PublicConstType
+    )]
+    #[const_type(
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        PrivateConstType
+    )]
+    #[const_type(
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub(crate) CrateConstType
+    )]
+    pub(crate) enum Direction {
+        Left,
+        Right,
+    }
+
+    pub(crate) fn private_const_type_value() -> Option<Direction> {
+        <PrivateConstType<{ Direction::Right as usize }> as optionally_const::OptionallyConst<
+            Direction,
+        >>::MAYBE_CONST
+    }
+}
+
 impl<const DISCRIMINANT: usize> std::fmt::Debug for ConstTypeName<DISCRIMINANT> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "ConstTypeName<{DISCRIMINANT}>")
@@ -128,7 +342,7 @@ fn test_try_from_another() {
         <FieldlessEnum as OptionallyConst<FieldlessEnum>>::try_from_another(
             ConstTypeName::<{ FieldlessEnum::B as usize }>,
         ),
-        Err(ConstTypeName::<{ FieldlessEnum::B as usize }>)
+        Ok(FieldlessEnum::B)
     );
     assert_eq!(
         <FieldlessEnum as OptionallyConst<FieldlessEnum>>::try_from_another(FieldlessEnum::A,),
@@ -136,10 +350,472 @@ fn test_try_from_another() {
     );
 }
 
+fn test_try_into_const() {
+    assert_eq!(
+        FieldlessEnum::A.try_into_const::<ConstTypeName<{ FieldlessEnum::A as usize }>>(),
+        Ok(ConstTypeName::<{ FieldlessEnum::A as usize }>)
+    );
+    assert_eq!(
+        FieldlessEnum::B.try_into_const::<ConstTypeName<{ FieldlessEnum::A as usize }>>(),
+        Err(FieldlessEnum::B)
+    );
+}
+
+fn test_explicit_discriminants() {
+    assert_eq!(FlagFieldlessEnum::C as usize, 4);
+    assert_eq!(FlagConstTypeName::<4>::MAYBE_CONST, Some(FlagFieldlessEnum::C));
+    assert_eq!(FlagConstTypeName::<2>::MAYBE_CONST, Some(FlagFieldlessEnum::B));
+    assert_eq!(
+        FlagFieldlessEnum::C.try_into_const_type_instance::<4>(),
+        Ok(FlagConstTypeName::<4>)
+    );
+    assert_eq!(
+        FlagFieldlessEnum::A.try_into_const_type_instance::<{ FlagFieldlessEnum::A as usize }>(),
+        Ok(FlagConstTypeName::<1>)
+    );
+    assert_eq!(
+        FlagFieldlessEnum::B.try_into_const_type_instance::<4>(),
+        Err(FlagFieldlessEnum::B)
+    );
+    assert_eq!(FlagFieldlessEnum::discriminant_bits(), 3);
+}
+
+fn test_default() {
+    #[derive(Default)]
+    struct Builder {
+        variant: ConstTypeName<{ FieldlessEnum::B as usize }>,
+    }
+
+    let builder = Builder::default();
+    assert_eq!(builder.variant, ConstTypeName::<{ FieldlessEnum::B as usize }>);
+    let variant: FieldlessEnum = builder.variant.into_value();
+    assert_eq!(variant, FieldlessEnum::B);
+}
+
+fn shape_const_type<T: OptionallyConst<Shape>>(_value: T) -> Option<Shape> {
+    T::MAYBE_CONST
+}
+
+fn shape_marker<T: OptionallyConst<Shape>>(value: T) -> Shape {
+    value.into_value()
+}
+
+fn test_multiple_const_types() {
+    assert_eq!(
+        shape_const_type(ShapeConstType::<{ Shape::Circle as usize }>),
+        Some(Shape::Circle)
+    );
+    assert_eq!(shape_const_type(Shape::Circle), None);
+    assert_eq!(
+        shape_marker(ShapeMarker::<{ Shape::Square as usize }>),
+        Shape::Square
+    );
+    assert_eq!(
+        ShapeMarker::<{ Shape::Circle as usize }>::try_from_value(Shape::Square),
+        Err(Shape::Square)
+    );
+    assert_eq!(
+        Shape::Square.try_into_const_type_instance::<{ Shape::Square as usize }>(),
+        Ok(ShapeConstType::<{ Shape::Square as usize }>)
+    );
+}
+
+fn test_partial_eq() {
+    assert!(ConstTypeName::<{ FieldlessEnum::A as usize }> == FieldlessEnum::A);
+    assert!(ConstTypeName::<{ FieldlessEnum::A as usize }> != FieldlessEnum::B);
+    assert!(FieldlessEnum::C == ConstTypeName::<{ FieldlessEnum::C as usize }>);
+    assert!(FieldlessEnum::C != ConstTypeName::<{ FieldlessEnum::B as usize }>);
+    assert!(FlagConstTypeName::<4> == FlagFieldlessEnum::C);
+    assert!(FlagConstTypeName::<2> != FlagFieldlessEnum::C);
+}
+
+fn test_cross_discriminant_partial_eq() {
+    let a = ConstTypeName::<{ FieldlessEnum::A as usize }>;
+    let b = ConstTypeName::<{ FieldlessEnum::B as usize }>;
+    assert!(a != b);
+    assert!(b != a);
+    assert!(a == ConstTypeName::<{ FieldlessEnum::A as usize }>);
+
+    fn same_variant<L, R>(lhs: L, rhs: R) -> bool
+    where
+        L: PartialEq<R>,
+    {
+        lhs == rhs
+    }
+
+    assert!(same_variant(b, ConstTypeName::<{ FieldlessEnum::B as usize }>));
+    assert!(!same_variant(b, ConstTypeName::<{ FieldlessEnum::C as usize }>));
+    assert!(FlagConstTypeName::<4> != FlagConstTypeName::<2>);
+}
+
+fn test_discriminant_bits() {
+    assert_eq!(FieldlessEnum::discriminant_bits(), 2);
+    assert_eq!(LargeFieldlessEnum::discriminant_bits(), 8);
+    assert_eq!(LargeFieldlessEnum::A as usize, 0);
+}
+
+fn test_hash() {
+    let mut set = std::collections::HashSet::new();
+    assert!(set.insert(HashableConstTypeName::<{ HashableFieldlessEnum::A as usize }>));
+    assert!(!set.insert(HashableConstTypeName::<{ HashableFieldlessEnum::A as usize }>));
+    assert_eq!(set.len(), 1);
+
+    let mut set = std::collections::HashSet::new();
+    assert!(set.insert(HashableConstTypeName::<{ HashableFieldlessEnum::B as usize }>));
+    assert_eq!(set.len(), 1);
+}
+
+fn test_discriminant() {
+    let c = ConstTypeName::<{ FieldlessEnum::C as usize }>;
+    let c_ref: &ConstTypeName<{ FieldlessEnum::C as usize }> = &c;
+    assert_eq!(c_ref.discriminant(), FieldlessEnum::C as usize);
+    assert_eq!(FlagConstTypeName::<4>.discriminant(), FlagFieldlessEnum::C as usize);
+}
+
+fn test_visibility_override() {
+    use private::{Direction, PublicConstType};
+
+    // `PublicConstType` is reachable from outside of the module only because of the `pub` override.
+    assert_eq!(
+        PublicConstType::<{ Direction::Left as usize }>::MAYBE_CONST,
+        Some(Direction::Left)
+    );
+    assert_eq!(private::private_const_type_value(), Some(Direction::Right));
+}
+
+fn test_align() {
+    assert_eq!(align_of::<AlignedConstType<{ Aligned::A as usize }>>(), 8);
+    assert_eq!(size_of::<AlignedConstType<{ Aligned::A as usize }>>(), 0);
+    assert_eq!(
+        AlignedConstType::<{ Aligned::B as usize }>::MAYBE_CONST,
+        Some(Aligned::B)
+    );
+}
+
+fn test_into() {
+    let b: FieldlessEnum = ConstTypeName::<{ FieldlessEnum::B as usize }>.into();
+    assert_eq!(b, FieldlessEnum::B);
+    let c = FlagFieldlessEnum::from(FlagConstTypeName::<4>);
+    assert_eq!(c, FlagFieldlessEnum::C);
+}
+
+fn test_custom_doc() {
+    assert_eq!(
+        DocumentedConstType::<{ LogLevel::Error as usize }>::MAYBE_CONST,
+        Some(LogLevel::Error)
+    );
+    let info: LogLevel = DocumentedConstType::<{ LogLevel::Info as usize }>.into();
+    assert_eq!(info, LogLevel::Info);
+}
+
+fn test_non_exhaustive() {
+    assert_eq!(
+        NonExhaustiveConstType::<{ NonExhaustive::B as usize }>::MAYBE_CONST,
+        Some(NonExhaustive::B)
+    );
+    assert_eq!(
+        NonExhaustive::A.try_into_const_type_instance::<{ NonExhaustive::A as usize }>(),
+        Ok(NonExhaustiveConstType::<{ NonExhaustive::A as usize }>)
+    );
+    assert_eq!(NonExhaustive::discriminant_bits(), 1);
+}
+
+fn test_as_enum() {
+    const B: FieldlessEnum = ConstTypeName::<{ FieldlessEnum::B as usize }>.as_enum();
+    const C: FlagFieldlessEnum = FlagConstTypeName::<4>.as_enum();
+    assert_eq!(B, FieldlessEnum::B);
+    assert_eq!(C, FlagFieldlessEnum::C);
+    assert_eq!(
+        ConstTypeName::<{ FieldlessEnum::A as usize }>.as_enum(),
+        FieldlessEnum::A
+    );
+}
+
+fn test_repr() {
+    assert_eq!(
+        ReprCConstType::<{ ReprC::B as usize }>::MAYBE_CONST,
+        Some(ReprC::B)
+    );
+    assert_eq!(
+        ReprC::A.try_into_const_type_instance::<{ ReprC::A as usize }>(),
+        Ok(ReprCConstType::<0>)
+    );
+    assert_eq!(ReprU8ConstType::<2>::MAYBE_CONST, Some(ReprU8::B));
+    assert_eq!(ReprU8ConstType::<1>.as_enum(), ReprU8::A);
+}
+
+fn test_const_carrier_for() {
+    let a: const_carrier_for!(ConstTypeName, FieldlessEnum::A) =
+        const_carrier_for!(ConstTypeName, FieldlessEnum::A);
+    assert_eq!(a, ConstTypeName::<{ FieldlessEnum::A as usize }>);
+    assert_eq!(
+        const_carrier_for!(FlagConstTypeName, FlagFieldlessEnum::C),
+        FlagConstTypeName::<4>
+    );
+    assert_eq!(
+        <const_carrier_for!(ConstTypeName, FieldlessEnum::C)>::MAYBE_CONST,
+        Some(FieldlessEnum::C)
+    );
+    assert_eq!(
+        const_carrier_for!(private::PublicConstType, private::Direction::Right).as_enum(),
+        private::Direction::Right
+    );
+}
+
+fn test_from_str() {
+    let a: ParsableConstType<{ Parsable::A as usize }> = "A".parse().unwrap();
+    assert_eq!(a, ParsableConstType::<{ Parsable::A as usize }>);
+    assert_eq!(
+        "B".parse::<ParsableConstType<{ Parsable::A as usize }>>(),
+        Err(optionally_const::ParseVariantError { expected: &["A"] })
+    );
+    assert_eq!(
+        "C".parse::<ParsableConstType<{ Parsable::B as usize }>>()
+            .unwrap_err()
+            .to_string(),
+        "expected `B`"
+    );
+}
+
+fn test_const_val() {
+    fn value_of<C: optionally_const::ConstVal>() -> C::Value {
+        C::VALUE
+    }
+
+    assert_eq!(
+        value_of::<ConstTypeName<{ FieldlessEnum::B as usize }>>(),
+        FieldlessEnum::B
+    );
+    assert_eq!(value_of::<FlagConstTypeName<4>>(), FlagFieldlessEnum::C);
+    // The overridden visibility is the same as the visibility of the enum.
+    assert_eq!(
+        value_of::<private::CrateConstType<{ private::Direction::Left as usize }>>(),
+        private::Direction::Left
+    );
+}
+
+fn test_each_const() {
+    let mut visited = Vec::new();
+    FieldlessEnum::each_const(|variant| visited.push(variant));
+    assert_eq!(
+        visited,
+        [FieldlessEnum::A, FieldlessEnum::B, FieldlessEnum::C]
+    );
+
+    let mut discriminants = Vec::new();
+    FlagFieldlessEnum::each_const(|variant| discriminants.push(variant as usize));
+    assert_eq!(discriminants, [1, 2, 4]);
+}
+
+fn test_display() {
+    assert_eq!(Displayable::A.to_string(), "A");
+    assert_eq!(
+        DisplayableConstType::<{ Displayable::A as usize }>.to_string(),
+        "A"
+    );
+    assert_eq!(
+        format!("{}", DisplayableConstType::<{ Displayable::B as usize }>),
+        Displayable::B.to_string()
+    );
+}
+
+fn test_cfg_variants() {
+    assert_eq!(
+        ConfiguredConstType::<{ Configured::C as usize }>::MAYBE_CONST,
+        Some(Configured::C)
+    );
+    assert_eq!(
+        ConfiguredConstType::<{ Configured::A as usize }>.as_enum(),
+        Configured::A
+    );
+    assert!("C".parse::<ConfiguredConstType<{ Configured::C as usize }>>().is_ok());
+    assert!("Never".parse::<ConfiguredConstType<{ Configured::C as usize }>>().is_err());
+
+    let mut visited = Vec::new();
+    Configured::each_const(|variant| visited.push(variant));
+
+    #[cfg(feature = "cfg_variant")]
+    {
+        assert_eq!(visited, [Configured::A, Configured::B, Configured::C]);
+        assert_eq!(
+            ConfiguredConstType::<{ Configured::B as usize }>::MAYBE_CONST,
+            Some(Configured::B)
+        );
+        assert_eq!(Configured::discriminant_bits(), 2);
+    }
+    #[cfg(not(feature = "cfg_variant"))]
+    {
+        assert_eq!(visited, [Configured::A, Configured::C]);
+        assert_eq!(Configured::discriminant_bits(), 1);
+    }
+}
+
+fn test_value_by_ref() {
+    struct Holder<T: OptionallyConst<FieldlessEnum>> {
+        variant: T,
+    }
+
+    let holder = Holder {
+        variant: ConstTypeName::<{ FieldlessEnum::C as usize }>,
+    };
+    let holder_ref: &Holder<_> = &holder;
+    let value: FieldlessEnum = holder_ref.variant.value_by_ref();
+    assert_eq!(value, FieldlessEnum::C);
+
+    let holder = Holder {
+        variant: FieldlessEnum::B,
+    };
+    assert_eq!(holder.variant.value_by_ref(), FieldlessEnum::B);
+}
+
+fn test_from_discriminant() {
+    assert_eq!(
+        FieldlessEnum::from_discriminant(FieldlessEnum::B as usize),
+        Some(FieldlessEnum::B)
+    );
+    assert_eq!(FieldlessEnum::from_discriminant(3), None);
+
+    const C: Option<FieldlessEnum> = FieldlessEnum::from_discriminant(FieldlessEnum::C as usize);
+    assert_eq!(C, Some(FieldlessEnum::C));
+
+    assert_eq!(
+        Configured::from_discriminant(Configured::C as usize),
+        Some(Configured::C)
+    );
+    assert_eq!(Configured::from_discriminant(Configured::C as usize + 1), None);
+}
+
+fn test_any() {
+    let any = ConstTypeNameAny::try_from(1usize);
+    assert!(matches!(any, Ok(ConstTypeNameAny::B(_))));
+    let Ok(ConstTypeNameAny::B(b)) = any else {
+        unreachable!()
+    };
+    assert_eq!(b.as_enum(), FieldlessEnum::B);
+
+    assert!(
+        ConstTypeNameAny::try_from(FieldlessEnum::C as usize)
+            == Ok(ConstTypeNameAny::C(ConstTypeName))
+    );
+    assert!(ConstTypeNameAny::try_from(3usize) == Err(3));
+
+    let flag = FlagConstTypeNameAny::try_from(FlagFieldlessEnum::C as usize);
+    assert!(matches!(flag, Ok(FlagConstTypeNameAny::C(_))));
+    assert!(FlagConstTypeNameAny::try_from(3usize).is_err());
+}
+
+fn test_inherent_value() {
+    const B: FieldlessEnum = ConstTypeName::<{ FieldlessEnum::B as usize }>::value();
+    assert_eq!(B, FieldlessEnum::B);
+    assert_eq!(FlagConstTypeName::<4>::value(), FlagFieldlessEnum::C);
+
+    let b: FieldlessEnum = ConstTypeName::<{ FieldlessEnum::B as usize }>.into_value();
+    assert_eq!(b, B);
+}
+
+fn test_where_clause() {
+    let a: BoundedConstType<{ Bounded::A as usize }> = BoundedConstType;
+    let value: Bounded = a.into_value();
+    assert_eq!(value, Bounded::A);
+    assert_eq!(
+        BoundedConstType::<{ Bounded::B as usize }>::MAYBE_CONST,
+        Some(Bounded::B)
+    );
+    assert_eq!(
+        Bounded::B.try_into_const_type_instance::<{ Bounded::B as usize }>(),
+        Ok(BoundedConstType)
+    );
+    assert!(
+        "A".parse::<BoundedConstType<{ Bounded::A as usize }>>()
+            .is_ok()
+    );
+}
+
+fn test_no_try_into() {
+    // Only `try_into_const_type_instance` is skipped, the rest of the API is generated.
+    let b: Minimal = MinimalConstType::<{ Minimal::B as usize }>.into_value();
+    assert_eq!(b, Minimal::B);
+    assert_eq!(Minimal::from_discriminant(0), Some(Minimal::A));
+    assert!(matches!(
+        MinimalConstTypeAny::try_from(Minimal::A as usize),
+        Ok(MinimalConstTypeAny::A(_))
+    ));
+}
+
+fn test_stable_discriminants() {
+    let a: Stable = StableConstType::<0>.into_value();
+    assert_eq!(a, Stable::A);
+    let b: Stable = StableConstType::<1>.into_value();
+    assert_eq!(b, Stable::B);
+}
+
+fn test_local_enum() {
+    // The derive is applied to an enum defined in a function body. The name of the const type
+    // shadows the module-level `ConstTypeName` within the function.
+    #[derive(FieldlessEnumConstType, Debug, Clone, Copy, PartialEq)]
+    #[const_type(
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        ConstTypeName,
+        from_str,
+    )]
+    enum Local {
+        A,
+        B,
+    }
+
+    let b: ConstTypeName<{ Local::B as usize }> = Local::B
+        .try_into_const_type_instance::<{ Local::B as usize }>()
+        .unwrap();
+    let value: Local = b.into_value();
+    assert_eq!(value, Local::B);
+    assert_eq!(
+        Local::A.try_into_const_type_instance::<{ Local::B as usize }>(),
+        Err(Local::A)
+    );
+    assert!(
+        "A".parse::<ConstTypeName<{ Local::A as usize }>>()
+            .is_ok()
+    );
+    assert!(matches!(
+        ConstTypeNameAny::try_from(1usize),
+        Ok(ConstTypeNameAny::B(_))
+    ));
+}
+
 fn main() {
     test_print_fieldless_enum();
     test_try_into_const_type_instance();
     test_maybe_const();
     test_try_from_value();
     test_try_from_another();
+    test_try_into_const();
+    test_explicit_discriminants();
+    test_default();
+    test_multiple_const_types();
+    test_partial_eq();
+    test_discriminant_bits();
+    test_hash();
+    test_discriminant();
+    test_visibility_override();
+    test_align();
+    test_into();
+    test_custom_doc();
+    test_non_exhaustive();
+    test_as_enum();
+    test_repr();
+    test_const_carrier_for();
+    test_from_str();
+    test_const_val();
+    test_each_const();
+    test_display();
+    test_cfg_variants();
+    test_value_by_ref();
+    test_from_discriminant();
+    test_cross_discriminant_partial_eq();
+    test_any();
+    test_inherent_value();
+    test_where_clause();
+    test_no_try_into();
+    test_stable_discriminants();
+    test_local_enum();
 }
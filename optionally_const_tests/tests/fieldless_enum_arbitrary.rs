@@ -0,0 +1,69 @@
+use arbitrary::{Arbitrary, Unstructured};
+use optionally_const::{ConstTypeBool, ConstTypeU8, FieldlessEnumConstType, OptionallyConst};
+
+#[derive(FieldlessEnumConstType, Debug, Clone, Copy, PartialEq)]
+#[const_type(
+    #[derive(Clone, Copy, PartialEq)]
+    ConstTypeName
+)]
+enum FieldlessEnum {
+    A,
+    B,
+}
+
+// A fuzz target input where the const types are interleaved with regular fields.
+#[derive(Arbitrary)]
+struct Input {
+    enabled: ConstTypeBool<true>,
+    first: u8,
+    version: ConstTypeU8<3>,
+    kind: ConstTypeName<{ FieldlessEnum::B as usize }>,
+    second: u8,
+}
+
+fn test_fuzz_input() {
+    // A tiny deterministic xorshift generator stands in for the fuzzer.
+    let mut state: u32 = 0x9e37_79b9;
+    for _ in 0..256 {
+        let mut data = [0u8; 2];
+        for byte in &mut data {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            *byte = state as u8;
+        }
+
+        let mut u = Unstructured::new(&data);
+        let input = Input::arbitrary(&mut u).unwrap();
+
+        // The const types don't consume any entropy, so the regular fields get all of it.
+        assert_eq!((input.first, input.second), (data[0], data[1]));
+        let enabled: bool = input.enabled.into_value();
+        let version: u8 = input.version.into_value();
+        let kind: FieldlessEnum = input.kind.into_value();
+        assert!(enabled);
+        assert_eq!(version, 3);
+        assert_eq!(kind, FieldlessEnum::B);
+    }
+}
+
+fn test_size_hint() {
+    assert_eq!(
+        <ConstTypeName<{ FieldlessEnum::A as usize }> as Arbitrary>::size_hint(0),
+        (0, Some(0))
+    );
+    assert_eq!(<ConstTypeBool<false> as Arbitrary>::size_hint(0), (0, Some(0)));
+}
+
+fn test_empty_input() {
+    let mut u = Unstructured::new(&[]);
+    let kind = ConstTypeName::<{ FieldlessEnum::A as usize }>::arbitrary(&mut u).unwrap();
+    assert!(kind == FieldlessEnum::A);
+    assert!(ConstTypeBool::<true>::arbitrary_take_rest(Unstructured::new(&[])).is_ok());
+}
+
+fn main() {
+    test_fuzz_input();
+    test_size_hint();
+    test_empty_input();
+}
@@ -0,0 +1,46 @@
+use bytemuck::{Pod, Zeroable};
+use optionally_const::{ConstTypeBool, ConstTypeU8, FieldlessEnumConstType};
+
+#[derive(FieldlessEnumConstType, Debug, Clone, Copy, PartialEq)]
+#[const_type(
+    #[derive(Clone, Copy, PartialEq)]
+    ConstTypeName
+)]
+enum FieldlessEnum {
+    A,
+    B,
+}
+
+// The const types are zero-sized, so they can be used as marker fields
+// without introducing padding.
+#[derive(Clone, Copy, Zeroable, Pod)]
+#[repr(C)]
+struct Vertex {
+    position: [f32; 2],
+    normalized: ConstTypeBool<true>,
+    version: ConstTypeU8<1>,
+    kind: ConstTypeName<{ FieldlessEnum::B as usize }>,
+}
+
+fn test_zeroed() {
+    let vertex: Vertex = Zeroable::zeroed();
+    assert_eq!(vertex.position, [0.0, 0.0]);
+    let kind: FieldlessEnum = optionally_const::OptionallyConst::into_value(vertex.kind);
+    assert_eq!(kind, FieldlessEnum::B);
+}
+
+fn test_bytes_of() {
+    let vertex = Vertex {
+        position: [1.0, 2.0],
+        normalized: ConstTypeBool::<true>::new(),
+        version: ConstTypeU8::<1>::new(),
+        kind: ConstTypeName::<{ FieldlessEnum::B as usize }>,
+    };
+    assert_eq!(bytemuck::bytes_of(&vertex).len(), size_of::<[f32; 2]>());
+    let _: &ConstTypeName<{ FieldlessEnum::A as usize }> = bytemuck::cast_ref(&());
+}
+
+fn main() {
+    test_zeroed();
+    test_bytes_of();
+}
@@ -0,0 +1,27 @@
+use optionally_const::FieldlessEnumConstType;
+
+#[derive(FieldlessEnumConstType, Debug, Clone, Copy, PartialEq)]
+#[const_type(
+    #[derive(Clone, Copy, PartialEq)]
+    ConstTypeName
+)]
+enum FieldlessEnum {
+    A,
+    B,
+    C,
+}
+
+// `defmt` output can't be easily inspected on the host, so we only check
+// that the `defmt::Format` impls exist.
+fn assert_format<T: defmt::Format>() {}
+
+fn test_format() {
+    assert_format::<ConstTypeName<{ FieldlessEnum::A as usize }>>();
+    assert_format::<ConstTypeName<{ FieldlessEnum::B as usize }>>();
+    assert_format::<ConstTypeName<{ FieldlessEnum::C as usize }>>();
+    assert_format::<optionally_const::ConstTypeBool<true>>();
+}
+
+fn main() {
+    test_format();
+}
@@ -0,0 +1,77 @@
+use optionally_const::{OptionallyConst, ParseVariantError, fieldless_enum};
+
+fieldless_enum!(
+    /// A fieldless enum declared with the `fieldless_enum!` macro.
+    pub FieldlessEnum { A, B, C } as ConstTypeName
+);
+
+fn print_fieldless_enum<T>(value: T)
+where
+    T: OptionallyConst<FieldlessEnum>,
+{
+    if let Some(value) = T::MAYBE_CONST {
+        println!("Const value: {value}");
+    } else {
+        let value: FieldlessEnum = T::into_value(value);
+        println!("Non-const value: {value}");
+    }
+}
+
+fn test_parse() {
+    assert_eq!("A".parse::<FieldlessEnum>(), Ok(FieldlessEnum::A));
+    assert_eq!("B".parse::<FieldlessEnum>(), Ok(FieldlessEnum::B));
+    assert_eq!("C".parse::<FieldlessEnum>(), Ok(FieldlessEnum::C));
+    assert_eq!(
+        "D".parse::<FieldlessEnum>(),
+        Err(ParseVariantError { expected: &["A", "B", "C"] })
+    );
+    assert_eq!(
+        "D".parse::<FieldlessEnum>().unwrap_err().to_string(),
+        "expected one of `A`, `B`, `C`"
+    );
+}
+
+fn test_display() {
+    assert_eq!(FieldlessEnum::A.to_string(), "A");
+    assert_eq!(FieldlessEnum::B.to_string(), "B");
+    assert_eq!(FieldlessEnum::C.to_string(), "C");
+}
+
+fn test_try_from_usize() {
+    assert_eq!(FieldlessEnum::try_from(0usize), Ok(FieldlessEnum::A));
+    assert_eq!(FieldlessEnum::try_from(2usize), Ok(FieldlessEnum::C));
+    assert_eq!(FieldlessEnum::try_from(3usize), Err(3));
+}
+
+fn test_all() {
+    assert_eq!(
+        FieldlessEnum::ALL,
+        [FieldlessEnum::A, FieldlessEnum::B, FieldlessEnum::C]
+    );
+}
+
+fn test_const_type() {
+    print_fieldless_enum(FieldlessEnum::A);
+    print_fieldless_enum(ConstTypeName::<{ FieldlessEnum::B as usize }>);
+    assert_eq!(
+        ConstTypeName::<{ FieldlessEnum::B as usize }>::MAYBE_CONST,
+        Some(FieldlessEnum::B),
+    );
+}
+
+fn test_round_trip() {
+    for variant in FieldlessEnum::ALL {
+        let parsed: FieldlessEnum = variant.to_string().parse().unwrap();
+        assert_eq!(parsed, variant);
+        assert_eq!(FieldlessEnum::try_from(variant as usize), Ok(variant));
+    }
+}
+
+fn main() {
+    test_parse();
+    test_display();
+    test_try_from_usize();
+    test_all();
+    test_const_type();
+    test_round_trip();
+}
@@ -0,0 +1,72 @@
+use optionally_const::{FieldlessEnumConstType, OptionallyConst};
+use proptest::test_runner::TestRunner;
+
+#[derive(FieldlessEnumConstType, Debug, Clone, Copy, PartialEq)]
+#[const_type(
+    #[derive(Clone, Copy, PartialEq)]
+    ConstTypeName
+)]
+enum FieldlessEnum {
+    A,
+    B,
+    C,
+}
+
+// An enum without `Debug` still derives the const type, just without the strategy.
+#[derive(FieldlessEnumConstType, Clone, Copy, PartialEq)]
+#[const_type(
+    #[derive(Clone, Copy, PartialEq)]
+    NoDebugConstType
+)]
+enum NoDebug {
+    A,
+}
+
+fn print_fieldless_enum<T>(value: T) -> FieldlessEnum
+where
+    T: OptionallyConst<FieldlessEnum>,
+{
+    let value: FieldlessEnum = value.into_value();
+    if T::MAYBE_CONST.is_some() {
+        println!("value is const: {value:?}");
+    } else {
+        println!("value is not const: {value:?}");
+    }
+    value
+}
+
+fn test_strategy() {
+    let mut runner = TestRunner::deterministic();
+    runner
+        .run(&FieldlessEnum::strategy(), |value| {
+            assert_eq!(print_fieldless_enum(value), value);
+            Ok(())
+        })
+        .unwrap();
+}
+
+fn test_strategy_covers_all_variants() {
+    use proptest::strategy::{Strategy, ValueTree};
+
+    let mut runner = TestRunner::deterministic();
+    let mut seen = [false; 3];
+    for _ in 0..256 {
+        let value = FieldlessEnum::strategy()
+            .new_tree(&mut runner)
+            .unwrap()
+            .current();
+        seen[value as usize] = true;
+    }
+    assert_eq!(seen, [true; 3]);
+}
+
+fn test_no_debug() {
+    let a: NoDebug = NoDebugConstType::<{ NoDebug::A as usize }>.into_value();
+    assert!(a == NoDebug::A);
+}
+
+fn main() {
+    test_strategy();
+    test_strategy_covers_all_variants();
+    test_no_debug();
+}
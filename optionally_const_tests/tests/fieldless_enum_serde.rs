@@ -0,0 +1,58 @@
+use optionally_const::{FieldlessEnumConstType, OptionallyConst};
+use serde::{Deserialize, Serialize};
+
+#[derive(FieldlessEnumConstType, Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[const_type(
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    ConstTypeName
+)]
+enum FieldlessEnum {
+    A,
+    B,
+    C,
+}
+
+// The derive must keep working for enums that don't implement serde traits.
+#[derive(FieldlessEnumConstType, Debug, Clone, Copy, PartialEq)]
+#[const_type(
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    NonSerdeConstTypeName
+)]
+enum NonSerdeFieldlessEnum {
+    A,
+}
+
+fn test_serialize() {
+    assert_eq!(
+        serde_json::to_string(&ConstTypeName::<{ FieldlessEnum::B as usize }>).unwrap(),
+        serde_json::to_string(&FieldlessEnum::B).unwrap(),
+    );
+}
+
+fn test_deserialize() {
+    let json = serde_json::to_string(&FieldlessEnum::B).unwrap();
+    assert_eq!(
+        serde_json::from_str::<ConstTypeName<{ FieldlessEnum::B as usize }>>(&json).unwrap(),
+        ConstTypeName::<{ FieldlessEnum::B as usize }>,
+    );
+
+    let err = serde_json::from_str::<ConstTypeName<{ FieldlessEnum::A as usize }>>(&json)
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("expected the `FieldlessEnum::A` variant")
+    );
+}
+
+fn test_non_serde_enum() {
+    assert_eq!(
+        NonSerdeConstTypeName::<{ NonSerdeFieldlessEnum::A as usize }>::MAYBE_CONST,
+        Some(NonSerdeFieldlessEnum::A),
+    );
+}
+
+fn main() {
+    test_serialize();
+    test_deserialize();
+    test_non_serde_enum();
+}
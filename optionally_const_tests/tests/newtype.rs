@@ -0,0 +1,50 @@
+use optionally_const::{Const, ConstVal, OptionallyConst, OptionallyConstNewtype};
+
+#[derive(OptionallyConstNewtype, Debug, Clone, Copy, PartialEq)]
+struct Enabled(bool);
+
+#[derive(OptionallyConstNewtype, Debug, Clone, Copy, PartialEq)]
+pub struct Retries(u8);
+
+fn describe<T: OptionallyConst<Enabled>>(enabled: T) -> String {
+    let Enabled(value) = enabled.into_value();
+    match T::MAYBE_CONST {
+        Some(_) => format!("const {value}"),
+        None => format!("runtime {value}"),
+    }
+}
+
+fn test_generic_function() {
+    assert_eq!(describe(Enabled(true)), "runtime true");
+    assert_eq!(describe(ConstTypeEnabled::<true>::new()), "const true");
+    assert_eq!(describe(ConstTypeEnabled::<false>::new()), "const false");
+}
+
+fn test_const() {
+    assert_eq!(
+        <ConstTypeEnabled<true> as Const<Enabled>>::VALUE,
+        Enabled(true)
+    );
+    const RETRIES: Retries = ConstTypeRetries::<3>::value();
+    assert_eq!(RETRIES, Retries(3));
+
+    fn value_of<C: ConstVal>() -> C::Value {
+        C::VALUE
+    }
+    assert_eq!(value_of::<ConstTypeRetries<5>>(), Retries(5));
+}
+
+fn test_try_from_value() {
+    assert!(ConstTypeEnabled::<true>::try_from_value(Enabled(true)).is_ok());
+    assert_eq!(
+        ConstTypeEnabled::<true>::try_from_value(Enabled(false)).err(),
+        Some(Enabled(false))
+    );
+    assert!(<Enabled as OptionallyConst<Enabled>>::try_from_value(Enabled(false)).is_ok());
+}
+
+fn main() {
+    test_generic_function();
+    test_const();
+    test_try_from_value();
+}
@@ -0,0 +1,17 @@
+use arbitrary::{Arbitrary, Unstructured};
+use optionally_const::FieldlessEnumConstType;
+
+#[derive(FieldlessEnumConstType, Clone, Copy, PartialEq)]
+#[const_type(
+    #[derive(Clone, Copy, PartialEq)]
+    ConstTypeName
+)]
+enum FieldlessEnum {
+    A,
+    B,
+}
+
+fn main() {
+    let mut u = Unstructured::new(&[]);
+    let _ = ConstTypeName::<999>::arbitrary(&mut u);
+}
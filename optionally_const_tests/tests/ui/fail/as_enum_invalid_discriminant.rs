@@ -0,0 +1,16 @@
+use optionally_const::FieldlessEnumConstType;
+
+#[derive(FieldlessEnumConstType, Clone, Copy, PartialEq)]
+#[const_type(
+    #[derive(Clone, Copy, PartialEq)]
+    ConstTypeName,
+    no_try_into,
+)]
+enum FieldlessEnum {
+    A,
+    B,
+}
+
+fn main() {
+    let _ = ConstTypeName::<99>.as_enum();
+}
@@ -0,0 +1,5 @@
+use optionally_const::assert_const;
+
+fn main() {
+    assert_const!(bool, bool);
+}
@@ -0,0 +1,13 @@
+use optionally_const::FieldlessEnumConstType;
+
+#[derive(FieldlessEnumConstType, Clone, Copy, PartialEq)]
+#[const_type(
+    #[derive(Clone, Copy, PartialEq)]
+    ConstTypeName
+)]
+enum FieldlessEnum<'a> {
+    A,
+    B,
+}
+
+fn main() {}
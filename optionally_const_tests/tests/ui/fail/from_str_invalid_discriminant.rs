@@ -0,0 +1,16 @@
+use optionally_const::FieldlessEnumConstType;
+
+#[derive(FieldlessEnumConstType, Clone, Copy, PartialEq)]
+#[const_type(
+    #[derive(Clone, Copy, PartialEq)]
+    ConstTypeName,
+    from_str,
+)]
+enum FieldlessEnum {
+    A,
+    B,
+}
+
+fn main() {
+    let _ = "A".parse::<ConstTypeName<99>>();
+}
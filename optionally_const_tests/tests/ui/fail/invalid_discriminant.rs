@@ -0,0 +1,15 @@
+use optionally_const::FieldlessEnumConstType;
+
+#[derive(FieldlessEnumConstType, Clone, Copy, PartialEq)]
+#[const_type(
+    #[derive(Clone, Copy, PartialEq)]
+    ConstTypeName
+)]
+enum FieldlessEnum {
+    A,
+    B,
+}
+
+fn main() {
+    let _ = FieldlessEnum::A.try_into_const_type_instance::<5>();
+}
@@ -0,0 +1,20 @@
+#![deny(unused_must_use)]
+
+use optionally_const::{ConstTypeBool, FieldlessEnumConstType, OptionallyConst};
+
+#[derive(FieldlessEnumConstType, Clone, Copy, PartialEq)]
+#[const_type(
+    #[derive(Clone, Copy, PartialEq)]
+    ConstTypeName
+)]
+enum FieldlessEnum {
+    A,
+    B,
+}
+
+fn main() {
+    OptionallyConst::<bool>::into_value(ConstTypeBool::<true>::new());
+    OptionallyConst::<bool>::const_value(true);
+    FieldlessEnum::B.try_into_const_type_instance::<{ FieldlessEnum::B as usize }>();
+    ConstTypeName::<{ FieldlessEnum::A as usize }>.as_enum();
+}
@@ -0,0 +1,9 @@
+use optionally_const::Const;
+
+fn expect_const<C: Const<u32>>(_: C) -> u32 {
+    C::VALUE
+}
+
+fn main() {
+    let _ = expect_const(42u32);
+}
@@ -0,0 +1,14 @@
+use optionally_const::FieldlessEnumConstType;
+
+#[derive(FieldlessEnumConstType, Clone, Copy, PartialEq)]
+#[const_type(
+    #[derive(Clone, Copy, PartialEq)]
+    ConstTypeName,
+    cross_eq,
+)]
+enum FieldlessEnum {
+    A,
+    B,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use optionally_const::FieldlessEnumConstType;
+
+#[derive(FieldlessEnumConstType, Clone, Copy, PartialEq)]
+#[const_type(
+    #[derive(Clone, Copy)]
+    ConstTypeName,
+    stable_discriminants,
+)]
+enum FieldlessEnum {
+    A = 0,
+    B,
+    C = 2,
+    D,
+}
+
+fn main() {}
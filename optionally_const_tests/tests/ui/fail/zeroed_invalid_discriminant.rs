@@ -0,0 +1,16 @@
+use bytemuck::Zeroable;
+use optionally_const::FieldlessEnumConstType;
+
+#[derive(FieldlessEnumConstType, Clone, Copy, PartialEq)]
+#[const_type(
+    #[derive(Clone, Copy, PartialEq)]
+    ConstTypeName
+)]
+enum FieldlessEnum {
+    A,
+    B,
+}
+
+fn main() {
+    let _: ConstTypeName<999> = Zeroable::zeroed();
+}
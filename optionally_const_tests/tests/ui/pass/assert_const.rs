@@ -0,0 +1,11 @@
+use optionally_const::{assert_const, ConstTypeBool, OptionallyConst};
+
+fn const_flag<T: OptionallyConst<bool>>(flag: T) -> bool {
+    assert_const!(T);
+    flag.into_value()
+}
+
+fn main() {
+    assert_const!(ConstTypeBool<true>, bool);
+    assert!(const_flag(ConstTypeBool::<true>::new()));
+}